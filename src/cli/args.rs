@@ -1,4 +1,6 @@
-use clap::{Parser, Subcommand};
+use crate::cli::filter::SortKey;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -8,6 +10,12 @@ use clap::{Parser, Subcommand};
 pub struct Cli {
     #[command(subcommand)]
     pub cmd: Option<Commands>,
+    #[arg(long, value_name = "FILE", help = "Run a file of REPL commands, one per line ('-' or 'clara -' for stdin)")]
+    pub script: Option<PathBuf>,
+    #[arg(long, help = "Stop a script at the first failing command")]
+    pub stop_on_error: bool,
+    #[arg(long, help = "Undo every change made by a script if any command fails")]
+    pub transactional: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -18,19 +26,251 @@ pub enum Commands {
         #[arg(help = "Task with slash-style metadata: 'title /p high /due 2025-08-10 /tag work'")]
         text: Option<String>,
     },
-    List,
+    List {
+        #[arg(short, long, help = "Include tasks scheduled to start later")]
+        all: bool,
+        #[arg(long, value_enum, help = "Show a flat list in this order")]
+        sort: Option<SortKey>,
+        #[arg(
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            help = "Slash-style filters: '/tag work,urgent'"
+        )]
+        filter: Vec<String>,
+    },
     Update {
-        #[arg(help = "ID of the task to update")]
-        id: u32,
-        #[arg(help = "Slash-style metadata updates: '/p high /tag work,urgent'")]
-        text: Option<String>,
+        #[arg(long, help = "Show what would change without changing anything")]
+        dry_run: bool,
+        #[arg(short, long, help = "Skip the confirmation asked for above bulk.confirm_over tasks")]
+        yes: bool,
+        #[arg(
+            required = true,
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            help = "Task IDs ('3', '3,5,7-9') then slash-style changes, or a filter then '--': '/tag sprint12 -- /p high'"
+        )]
+        target: Vec<String>,
+    },
+    Edit {
+        #[arg(long, required = true, help = "Edit every task matching the filter in one file")]
+        bulk: bool,
+        #[arg(long, help = "Edit titles and statuses as a markdown checklist instead of TOML")]
+        markdown: bool,
+        #[arg(short, long, help = "Skip the confirmation asked for when removing more than bulk.confirm_over tasks")]
+        yes: bool,
+        #[arg(
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            help = "Slash-style filters: '/tag sprint12 /status todo'"
+        )]
+        filter: Vec<String>,
     },
     Remove {
-        #[arg(help = "ID of the task to remove")]
-        id: u32,
+        #[arg(long, help = "Show what would change without changing anything")]
+        dry_run: bool,
+        #[arg(short, long, help = "Skip the confirmation asked for above bulk.confirm_over tasks")]
+        yes: bool,
+        #[arg(
+            required = true,
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            help = "Task IDs ('3', '3,5,7-9') or a slash filter such as '/tag sprint12'"
+        )]
+        target: Vec<String>,
     },
     Done {
-        #[arg(help = "ID of the task to mark as done")]
+        #[arg(long, help = "Show what would change without changing anything")]
+        dry_run: bool,
+        #[arg(short, long, help = "Skip the confirmation asked for above bulk.confirm_over tasks")]
+        yes: bool,
+        #[arg(
+            required = true,
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            help = "Task IDs ('3', '3,5,7-9') or a slash filter such as '/tag sprint12'"
+        )]
+        target: Vec<String>,
+    },
+    Note {
+        #[arg(help = "ID of the task to annotate")]
+        id: u32,
+        #[arg(help = "Note text, appended as a timestamped entry")]
+        text: String,
+    },
+    Show {
+        #[arg(help = "ID of the task to show")]
+        id: u32,
+    },
+    Tags,
+    Tag {
+        #[command(subcommand)]
+        action: TagAction,
+    },
+    View {
+        #[command(subcommand)]
+        action: ViewAction,
+    },
+    Today,
+    Upcoming {
+        #[arg(default_value_t = 7, help = "Number of days to look ahead")]
+        days: u32,
+    },
+    Overdue,
+    Track {
+        #[command(subcommand)]
+        action: TrackAction,
+    },
+    Timesheet {
+        #[arg(long, value_enum, default_value_t = TimesheetGrouping::Day, help = "How to group tracked time")]
+        by: TimesheetGrouping,
+        #[arg(long, help = "First day to include (default: 6 days before --to)")]
+        from: Option<String>,
+        #[arg(long, help = "Last day to include (default: today)")]
+        to: Option<String>,
+    },
+    Stats {
+        #[arg(long, value_enum, default_value_t = StatsPeriod::Day, help = "Bucket created/completed counts per day or week")]
+        by: StatsPeriod,
+        #[arg(long, default_value_t = 14, help = "How many days back to report throughput")]
+        days: u32,
+        #[arg(long, help = "Print the report as JSON")]
+        json: bool,
+    },
+    Report {
+        #[command(subcommand)]
+        kind: ReportKind,
+    },
+    #[command(about = "Show the most urgent actionable task")]
+    Next,
+    #[command(about = "Browse and triage tasks in a full-screen view")]
+    Tui,
+    #[command(about = "Show tasks as a kanban board with a column per status")]
+    Board {
+        #[arg(short, long, help = "Open the board full-screen and move cards between columns")]
+        interactive: bool,
+    },
+    #[command(about = "Show a month grid of due tasks")]
+    Calendar {
+        #[arg(help = "next, prev, YYYY-MM, a month number or name (default: this month)")]
+        month: Option<String>,
+    },
+    #[command(about = "Show or change settings in config.toml")]
+    Config {
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigAction {
+    #[command(about = "Print one setting, e.g. display.date_format")]
+    Get {
+        #[arg(help = "Dotted key: section.name")]
+        key: String,
+    },
+    #[command(about = "Change a setting and save config.toml")]
+    Set {
+        #[arg(help = "Dotted key: section.name")]
+        key: String,
+        #[arg(help = "New value")]
+        value: String,
+    },
+    #[command(about = "Remove a setting so its default applies again")]
+    Unset {
+        #[arg(help = "Dotted key: section.name")]
+        key: String,
+    },
+    #[command(about = "Print the location of config.toml")]
+    Path,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TagAction {
+    Rename {
+        #[arg(help = "Existing tag name")]
+        old: String,
+        #[arg(help = "New tag name")]
+        new: String,
+    },
+    Merge {
+        #[arg(required = true, help = "Tags to merge away")]
+        sources: Vec<String>,
+        #[arg(long, help = "Tag that replaces the merged tags")]
+        into: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ViewAction {
+    Save {
+        #[arg(help = "Name of the view")]
+        name: String,
+        #[arg(long, help = "Save the view for all projects instead of the current one")]
+        global: bool,
+        #[arg(
+            required = true,
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            help = "Slash-style filter: '/status todo /due <=today'"
+        )]
+        query: Vec<String>,
+    },
+    List,
+    Delete {
+        #[arg(help = "Name of the view to delete")]
+        name: String,
+    },
+    #[command(external_subcommand)]
+    Run(Vec<String>),
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TrackAction {
+    Start {
+        #[arg(help = "ID of the task to track")]
+        id: u32,
+    },
+    Stop,
+    Log {
+        #[arg(help = "ID of the task the time was spent on")]
         id: u32,
+        #[arg(help = "Time spent: 45m, 2h, 1h30m")]
+        duration: String,
     },
+    Status,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum TimesheetGrouping {
+    Day,
+    Week,
+    Project,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum StatsPeriod {
+    Day,
+    Week,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ReportKind {
+    #[command(about = "Remaining work per day against an ideal line")]
+    Burndown(ReportOptions),
+    #[command(alias = "cfd", about = "Tasks per status per day, stacked")]
+    Flow(ReportOptions),
+}
+
+#[derive(Args, Debug)]
+pub struct ReportOptions {
+    #[arg(long, help = "Project to report on (default: the current project, or all tasks)")]
+    pub project: Option<String>,
+    #[arg(long, help = "First day (default: 13 days before --to)")]
+    pub from: Option<String>,
+    #[arg(long, help = "Last day, may be in the future for a sprint end (default: today)")]
+    pub to: Option<String>,
+    #[arg(long, help = "Also write the chart as an SVG file")]
+    pub svg: Option<PathBuf>,
+    #[arg(long, help = "Draw with plain ASCII characters")]
+    pub ascii: bool,
 }
@@ -0,0 +1,234 @@
+// Terminal and SVG rendering for the burndown and cumulative flow reports
+
+const BAR_WIDTH: usize = 40;
+const SVG_WIDTH: f64 = 640.0;
+const SVG_HEIGHT: f64 = 320.0;
+const SVG_MARGIN: f64 = 40.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FlowCounts {
+    pub todo: u32,
+    pub in_progress: u32,
+    pub done: u32,
+}
+
+impl FlowCounts {
+    pub fn total(&self) -> u32 {
+        self.todo + self.in_progress + self.done
+    }
+
+    pub fn remaining(&self) -> u32 {
+        self.todo + self.in_progress
+    }
+}
+
+fn scale(value: f64, max: f64, width: usize) -> usize {
+    if max <= 0.0 {
+        return 0;
+    }
+    ((value / max) * width as f64).round() as usize
+}
+
+// One row per day: a bar for the remaining work and a marker on the ideal line.
+// Days without data (in the future) only show the marker.
+pub fn burndown_lines(labels: &[String], remaining: &[Option<u32>], ideal: &[f64], ascii: bool) -> Vec<String> {
+    let (fill, marker) = if ascii { ('#', '|') } else { ('█', '│') };
+    let max = remaining
+        .iter()
+        .flatten()
+        .map(|r| *r as f64)
+        .chain(ideal.iter().copied())
+        .fold(0.0, f64::max);
+
+    labels
+        .iter()
+        .zip(remaining)
+        .zip(ideal)
+        .map(|((label, remaining), ideal)| {
+            let bar = remaining.map(|r| scale(r as f64, max, BAR_WIDTH)).unwrap_or(0);
+            let ideal_at = scale(*ideal, max, BAR_WIDTH);
+            let mut cells: Vec<char> = vec![' '; BAR_WIDTH + 1];
+            for cell in cells.iter_mut().take(bar) {
+                *cell = fill;
+            }
+            cells[ideal_at.min(BAR_WIDTH)] = marker;
+
+            let value = match remaining {
+                Some(remaining) => remaining.to_string(),
+                None => "-".to_string(),
+            };
+            format!("  {:<10} {} {:>3}  (ideal {:.1})", label, cells.into_iter().collect::<String>(), value, ideal)
+        })
+        .collect()
+}
+
+// One stacked bar per day, scaled to the largest total: done, then in progress, then todo
+pub fn flow_lines(labels: &[String], counts: &[FlowCounts], ascii: bool) -> Vec<String> {
+    let (done, doing, todo) = if ascii { ('#', '=', '.') } else { ('█', '▓', '░') };
+    let max = counts.iter().map(|c| c.total()).max().unwrap_or(0) as f64;
+
+    labels
+        .iter()
+        .zip(counts)
+        .map(|(label, c)| {
+            let done_width = scale(c.done as f64, max, BAR_WIDTH);
+            let doing_width = scale((c.done + c.in_progress) as f64, max, BAR_WIDTH) - done_width;
+            let todo_width = scale(c.total() as f64, max, BAR_WIDTH) - done_width - doing_width;
+            let bar: String = std::iter::repeat_n(done, done_width)
+                .chain(std::iter::repeat_n(doing, doing_width))
+                .chain(std::iter::repeat_n(todo, todo_width))
+                .collect();
+            format!(
+                "  {:<10} {:<w$}  {} done, {} in progress, {} todo",
+                label,
+                bar,
+                c.done,
+                c.in_progress,
+                c.todo,
+                w = BAR_WIDTH
+            )
+        })
+        .collect()
+}
+
+pub fn flow_legend(ascii: bool) -> &'static str {
+    if ascii {
+        "# done  = in progress  . todo"
+    } else {
+        "█ done  ▓ in progress  ░ todo"
+    }
+}
+
+fn svg_point(index: usize, count: usize, value: f64, max: f64) -> (f64, f64) {
+    let plot_width = SVG_WIDTH - 2.0 * SVG_MARGIN;
+    let plot_height = SVG_HEIGHT - 2.0 * SVG_MARGIN;
+    let x = SVG_MARGIN + plot_width * index as f64 / (count.max(2) - 1) as f64;
+    let y = SVG_HEIGHT - SVG_MARGIN - if max > 0.0 { plot_height * value / max } else { 0.0 };
+    (x, y)
+}
+
+fn svg_frame(title: &str, labels: &[String], max: f64, body: &str) -> String {
+    let first = labels.first().map(String::as_str).unwrap_or("");
+    let last = labels.last().map(String::as_str).unwrap_or("");
+    let bottom = SVG_HEIGHT - SVG_MARGIN;
+    let right = SVG_WIDTH - SVG_MARGIN;
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="12">
+<rect width="100%" height="100%" fill="white"/>
+<text x="{m}" y="24" font-size="14">{title}</text>
+{body}<line x1="{m}" y1="{bottom}" x2="{right}" y2="{bottom}" stroke="#333"/>
+<line x1="{m}" y1="{m}" x2="{m}" y2="{bottom}" stroke="#333"/>
+<text x="{m}" y="{label_y}">{first}</text>
+<text x="{right}" y="{label_y}" text-anchor="end">{last}</text>
+<text x="{max_x}" y="{max_y}" text-anchor="end">{max}</text>
+<text x="{max_x}" y="{bottom}" text-anchor="end">0</text>
+</svg>
+"##,
+        w = SVG_WIDTH,
+        h = SVG_HEIGHT,
+        m = SVG_MARGIN,
+        title = escape_xml(title),
+        body = body,
+        bottom = bottom,
+        right = right,
+        label_y = bottom + 16.0,
+        first = escape_xml(first),
+        last = escape_xml(last),
+        max_x = SVG_MARGIN - 6.0,
+        max_y = SVG_MARGIN + 4.0,
+        max = max,
+    )
+}
+
+pub fn burndown_svg(title: &str, labels: &[String], remaining: &[Option<u32>], ideal: &[f64]) -> String {
+    let max = remaining
+        .iter()
+        .flatten()
+        .map(|r| *r as f64)
+        .chain(ideal.iter().copied())
+        .fold(0.0, f64::max);
+    let count = labels.len();
+
+    let points = |values: Vec<(usize, f64)>| {
+        values
+            .into_iter()
+            .map(|(i, v)| {
+                let (x, y) = svg_point(i, count, v, max);
+                format!("{:.1},{:.1}", x, y)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let ideal_points = points(ideal.iter().copied().enumerate().collect());
+    let actual_points = points(
+        remaining
+            .iter()
+            .enumerate()
+            .filter_map(|(i, r)| r.map(|r| (i, r as f64)))
+            .collect(),
+    );
+
+    let body = format!(
+        r##"<polyline points="{}" fill="none" stroke="#999" stroke-dasharray="6 4"/>
+<polyline points="{}" fill="none" stroke="#2563eb" stroke-width="2"/>
+"##,
+        ideal_points, actual_points
+    );
+    svg_frame(title, labels, max, &body)
+}
+
+pub fn flow_svg(title: &str, labels: &[String], counts: &[FlowCounts]) -> String {
+    let max = counts.iter().map(|c| c.total()).max().unwrap_or(0) as f64;
+    let count = labels.len();
+
+    // Stacked bands: each polygon runs along its upper edge and back along its lower edge
+    let band = |lower: &dyn Fn(&FlowCounts) -> u32, upper: &dyn Fn(&FlowCounts) -> u32, color: &str| {
+        let top = counts.iter().enumerate().map(|(i, c)| svg_point(i, count, upper(c) as f64, max));
+        let bottom = counts.iter().enumerate().rev().map(|(i, c)| svg_point(i, count, lower(c) as f64, max));
+        let points: Vec<String> = top.chain(bottom).map(|(x, y)| format!("{:.1},{:.1}", x, y)).collect();
+        format!("<polygon points=\"{}\" fill=\"{}\"/>\n", points.join(" "), color)
+    };
+
+    let body = [
+        band(&|_| 0, &|c| c.done, "#16a34a"),
+        band(&|c| c.done, &|c| c.done + c.in_progress, "#f59e0b"),
+        band(&|c| c.done + c.in_progress, &|c| c.total(), "#cbd5e1"),
+    ]
+    .concat();
+    svg_frame(title, labels, max, &body)
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burndown_lines_mark_ideal_and_future_days() {
+        let labels = vec!["Mon".to_string(), "Tue".to_string()];
+        let lines = burndown_lines(&labels, &[Some(4), None], &[4.0, 0.0], true);
+        assert!(lines[0].contains(&format!("{}| ", "#".repeat(BAR_WIDTH))));
+        assert!(lines[0].contains("  4  (ideal 4.0)"));
+        assert!(lines[1].contains("|") && !lines[1].contains('#'));
+        assert!(lines[1].contains("  -  (ideal 0.0)"));
+    }
+
+    #[test]
+    fn test_flow_lines_stack_statuses() {
+        let counts = FlowCounts { todo: 1, in_progress: 1, done: 2 };
+        let lines = flow_lines(&["Mon".to_string()], &[counts], true);
+        let bar = format!("{}{}{}", "#".repeat(20), "=".repeat(10), ".".repeat(10));
+        assert!(lines[0].contains(&bar));
+    }
+
+    #[test]
+    fn test_svg_output_is_escaped() {
+        let svg = flow_svg("Flow <work>", &["a".to_string()], &[FlowCounts::default()]);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("Flow &lt;work&gt;"));
+        assert_eq!(svg.matches("<polygon").count(), 3);
+    }
+}
@@ -1,18 +1,60 @@
 use crate::cli::metadata::parse_slash_metadata;
+use crate::config::Config;
+use crate::project::{Project, ProjectStore};
 use crate::repl::command_handler::ReplContext;
-use crate::task::{TaskDraft, TaskStore};
-use crate::editor::edit_toml_content;
+use crate::task::tags::apply_tag_changes;
+use crate::task::{Task, TaskDraft, TaskStore};
+use crate::editor::edit_until_valid;
 
 pub fn add_task(parent_id: Option<u32>, text: Option<String>, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
     // Check if we have slash-style metadata or should use TOML editor
     if let Some(text_input) = text {
-        return add_task_with_metadata(parent_id, &text_input, context);
+        add_task_with_metadata(parent_id, &text_input, context)
     } else {
-        return add_task_with_editor(parent_id, context);
+        add_task_with_editor(parent_id, context)
+    }
+}
+
+// The project in use, or defaults.project when none is
+pub fn target_project(context: &ReplContext) -> Result<Option<(u32, String)>, Box<dyn std::error::Error>> {
+    if let Some(project) = &context.current_project {
+        return Ok(Some((project.id, project.name.clone())));
+    }
+
+    match &Config::current().defaults.project {
+        Some(name) => match ProjectStore::new()?.find_by_name(name)? {
+            Some(project) => Ok(Some((project.id, project.name))),
+            None => Err(format!("Default project '{}' not found. Fix defaults.project with 'config set'", name).into()),
+        },
+        None => Ok(None),
     }
 }
 
 fn add_task_with_metadata(parent_id: Option<u32>, text: &str, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let project = target_project(context)?;
+    let task = task_from_text(&store, text, parent_id, project.as_ref().map(|(id, _)| *id))?;
+    store.insert(&task)?;
+    
+    let project_info = project
+        .map(|(_, name)| format!(" in project '{}'", name))
+        .unwrap_or_default();
+    
+    match task.parent_id {
+        Some(parent_id) => println!("✅ Subtask added to parent #{}{}: '{}'", parent_id, project_info, task.title),
+        None => println!("✅ Task added{}: '{}'", project_info, task.title),
+    }
+    
+    Ok(())
+}
+
+// Build a new task from 'title /p high /tag work' style text
+pub fn task_from_text(
+    store: &TaskStore,
+    text: &str,
+    parent_id: Option<u32>,
+    project_id: Option<u32>,
+) -> Result<Task, Box<dyn std::error::Error>> {
     // Parse slash metadata
     let metadata = parse_slash_metadata(text)?;
     
@@ -21,7 +63,6 @@ fn add_task_with_metadata(parent_id: Option<u32>, text: &str, context: &ReplCont
     
     // Validate parent exists if provided
     if let Some(parent_id) = final_parent_id {
-        let store = TaskStore::new()?;
         match store.find_by_id(parent_id)? {
             Some(_) => {},
             None => return Err(format!("Parent task with ID {} not found", parent_id).into()),
@@ -29,10 +70,10 @@ fn add_task_with_metadata(parent_id: Option<u32>, text: &str, context: &ReplCont
     }
     
     // Create TaskDraft from metadata
-    let mut task_draft = TaskDraft::new();
+    let mut task_draft = TaskDraft::from_defaults(&Config::current().defaults);
     task_draft.title = metadata.title;
     task_draft.parent_id = final_parent_id;
-    task_draft.project_id = context.current_project.as_ref().map(|p| p.id);
+    task_draft.project_id = project_id;
     
     if let Some(priority) = metadata.priority {
         task_draft.priority = priority;
@@ -41,24 +82,18 @@ fn add_task_with_metadata(parent_id: Option<u32>, text: &str, context: &ReplCont
     if let Some(due_date) = metadata.due_date {
         task_draft.due_date = Some(due_date);
     }
+    task_draft.scheduled_date = metadata.scheduled_date;
+    task_draft.estimate = metadata.estimate;
     
-    task_draft.tags = metadata.tags;
+    let mut tags = metadata.tags;
+    apply_tag_changes(&mut tags, &metadata.tags_added, &[]);
+    task_draft.tags = tags;
     
-    // Convert to task and save
-    let task = task_draft.to_task()?;
-    let store = TaskStore::new()?;
-    store.insert(&task)?;
-    
-    let project_info = context.current_project.as_ref()
-        .map(|p| format!(" in project '{}'", p.name))
-        .unwrap_or_default();
-    
-    match final_parent_id {
-        Some(parent_id) => println!("✅ Subtask added to parent #{}{}: '{}'", parent_id, project_info, task.title),
-        None => println!("✅ Task added{}: '{}'", project_info, task.title),
-    }
-    
-    Ok(())
+    Ok(task_draft.into_task()?)
+}
+
+pub fn parse_task(content: &str, projects: &[Project]) -> Result<Task, String> {
+    TaskDraft::from_toml(content, projects)?.into_task()
 }
 
 fn add_task_with_editor(parent_id: Option<u32>, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
@@ -72,27 +107,28 @@ fn add_task_with_editor(parent_id: Option<u32>, context: &ReplContext) -> Result
     }
 
     // Create TOML template
-    let mut template = TaskDraft::new();
+    let project = target_project(context)?;
+    let mut template = TaskDraft::from_defaults(&Config::current().defaults);
     template.parent_id = parent_id;
-    template.project_id = context.current_project.as_ref().map(|p| p.id);
-    let toml_content = template.to_toml()?;
-    
-    // Edit content using shared editor utility
-    let edited_content = edit_toml_content(&toml_content)?;
-    
-    // Parse TOML and convert to task
-    let task_draft = TaskDraft::from_toml(&edited_content)
-        .map_err(|e| format!("Invalid TOML: {}", e))?;
-    
-    let task = task_draft.to_task()
-        .map_err(|e| e)?;
+    template.project_id = project.as_ref().map(|(id, _)| *id);
+    let projects = ProjectStore::new()?.list()?;
+    let toml_content = template.to_toml(&projects)?;
+    
+    // Edit until the content parses into a valid task, or the user empties the file
+    let task = match edit_until_valid(&toml_content, |content| parse_task(content, &projects))? {
+        Some(task) => task,
+        None => {
+            println!("❌ Cancelled: the file was left empty.");
+            return Ok(());
+        }
+    };
     
     // Save task to database
     let store = TaskStore::new()?;
     store.insert(&task)?;
     
-    let project_info = context.current_project.as_ref()
-        .map(|p| format!(" in project '{}'", p.name))
+    let project_info = project
+        .map(|(_, name)| format!(" in project '{}'", name))
         .unwrap_or_default();
     
     match parent_id {
@@ -0,0 +1,215 @@
+use crate::cli::commands::list::TaskLineFormat;
+use crate::cli::format::{format_day, relative_due};
+use crate::repl::command_handler::ReplContext;
+use crate::task::dates::{end_of_day_timestamp, start_of_day_timestamp};
+use crate::task::{Status, Task, TaskStore};
+use chrono::{Duration, Local, NaiveDate};
+use std::collections::BTreeMap;
+
+struct Agenda {
+    line_format: TaskLineFormat,
+}
+
+impl Agenda {
+    fn new(context: &ReplContext) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Agenda { line_format: TaskLineFormat::load(context)? })
+    }
+
+    fn print_task(&self, task: &Task) {
+        let line = self.line_format.format(task);
+        match task.due_date_datetime() {
+            Some(due) => println!("    {} ({})", line, relative_due(due, Local::now())),
+            None => println!("    {}", line),
+        }
+    }
+
+    fn print_starting(&self, task: &Task) {
+        println!("    ▶ {} (starts)", self.line_format.format(task));
+    }
+
+    // Group tasks under the day they are due, along with tasks that become actionable that day
+    fn print_by_day(&self, due: &[Task], starting: &[Task]) {
+        let today = Local::now().date_naive();
+        let mut days: BTreeMap<NaiveDate, (Vec<&Task>, Vec<&Task>)> = BTreeMap::new();
+        for task in due {
+            if let Some(due) = task.due_date_datetime() {
+                days.entry(due.date_naive()).or_default().0.push(task);
+            }
+        }
+        for task in starting {
+            if let Some(scheduled) = task.scheduled_date_datetime() {
+                days.entry(scheduled.date_naive()).or_default().1.push(task);
+            }
+        }
+
+        for (day, (due, starting)) in days {
+            let marker = if day < today { "⚠️  " } else { "" };
+            println!("  {}{}", marker, day_label(day, today));
+            for task in due {
+                self.print_task(task);
+            }
+            for task in starting {
+                self.print_starting(task);
+            }
+        }
+    }
+}
+
+fn day_label(day: NaiveDate, today: NaiveDate) -> String {
+    let relative = match (day - today).num_days() {
+        0 => " (today)",
+        1 => " (tomorrow)",
+        -1 => " (yesterday)",
+        _ => "",
+    };
+    format!("{}{}", format_day(day), relative)
+}
+
+fn scope_label(context: &ReplContext) -> String {
+    context
+        .current_project
+        .as_ref()
+        .map(|p| format!(" in project '{}'", p.name))
+        .unwrap_or_default()
+}
+
+pub fn agenda_today(context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let agenda = Agenda::new(context)?;
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let today = Local::now().date_naive();
+
+    let due = store.find_open_due_before(end_of_day_timestamp(today)?, project_id)?;
+    let (overdue, due_today): (Vec<Task>, Vec<Task>) = due
+        .into_iter()
+        .partition(|t| t.due_date_datetime().is_some_and(|d| d.date_naive() < today));
+
+    // In-progress work without a due date today still belongs on the agenda
+    let in_progress: Vec<Task> = store
+        .find_by_status(Status::InProgress, project_id)?
+        .into_iter()
+        .filter(|t| !overdue.iter().chain(&due_today).any(|d| d.id == t.id))
+        .collect();
+
+    let starting = store.find_open_scheduled_between(
+        start_of_day_timestamp(today)?,
+        end_of_day_timestamp(today)?,
+        project_id,
+    )?;
+
+    println!("📅 Today{}: {}", scope_label(context), format_day(today));
+
+    if overdue.is_empty() && due_today.is_empty() && in_progress.is_empty() && starting.is_empty() {
+        println!("Nothing due today. 🎉");
+        return Ok(());
+    }
+
+    if !overdue.is_empty() {
+        println!();
+        println!("⚠️  Overdue ({})", overdue.len());
+        agenda.print_by_day(&overdue, &[]);
+    }
+
+    if !due_today.is_empty() {
+        println!();
+        println!("Due today ({})", due_today.len());
+        for task in &due_today {
+            agenda.print_task(task);
+        }
+    }
+
+    if !starting.is_empty() {
+        println!();
+        println!("▶ Starting today ({})", starting.len());
+        for task in &starting {
+            agenda.print_starting(task);
+        }
+    }
+
+    if !in_progress.is_empty() {
+        println!();
+        println!("🔄 In progress ({})", in_progress.len());
+        for task in &in_progress {
+            agenda.print_task(task);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn agenda_upcoming(days: u32, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let agenda = Agenda::new(context)?;
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let today = Local::now().date_naive();
+    let last_day = today + Duration::days(days.saturating_sub(1) as i64);
+
+    // Overdue tasks are listed first so they are not forgotten
+    let tasks = store.find_open_due_before(end_of_day_timestamp(last_day)?, project_id)?;
+    let starting = store.find_open_scheduled_between(
+        start_of_day_timestamp(today)?,
+        end_of_day_timestamp(last_day)?,
+        project_id,
+    )?;
+
+    println!(
+        "📅 Upcoming{}: next {} day(s), through {}",
+        scope_label(context),
+        days,
+        format_day(last_day)
+    );
+
+    if tasks.is_empty() && starting.is_empty() {
+        println!("Nothing due. 🎉");
+        return Ok(());
+    }
+
+    agenda.print_by_day(&tasks, &starting);
+
+    let in_progress: Vec<Task> = store
+        .find_by_status(Status::InProgress, project_id)?
+        .into_iter()
+        .filter(|t| t.due_date.is_none())
+        .collect();
+    if !in_progress.is_empty() {
+        println!("  🔄 In progress, no due date");
+        for task in &in_progress {
+            agenda.print_task(task);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn agenda_overdue(context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let agenda = Agenda::new(context)?;
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let yesterday = Local::now().date_naive() - Duration::days(1);
+
+    let overdue = store.find_open_due_before(end_of_day_timestamp(yesterday)?, project_id)?;
+
+    println!("⚠️  Overdue{} ({})", scope_label(context), overdue.len());
+    if overdue.is_empty() {
+        println!("Nothing overdue. 🎉");
+        return Ok(());
+    }
+
+    agenda.print_by_day(&overdue, &[]);
+
+    Ok(())
+}
+
+// Counts of overdue tasks and tasks due today, for the REPL banner
+pub fn agenda_counts(project_id: Option<u32>) -> Result<(usize, usize), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let today = Local::now().date_naive();
+
+    let due = store.find_open_due_before(end_of_day_timestamp(today)?, project_id)?;
+    let overdue = due
+        .iter()
+        .filter(|t| t.due_date_datetime().is_some_and(|d| d.date_naive() < today))
+        .count();
+
+    Ok((overdue, due.len() - overdue))
+}
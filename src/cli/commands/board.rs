@@ -0,0 +1,159 @@
+use crate::repl::command_handler::ReplContext;
+use crate::task::model::Priority;
+use crate::task::{Status, Task, TaskStore};
+use crate::tui::board::run_board;
+use chrono::Local;
+
+// Done cards are capped so a long history does not swamp the board
+const DONE_LIMIT: usize = 10;
+
+pub const COLUMN_TITLES: [&str; 3] = ["Todo", "In progress", "Done"];
+
+pub fn column_index(status: &Status) -> usize {
+    match status {
+        Status::Todo => 0,
+        Status::InProgress => 1,
+        Status::Done => 2,
+    }
+}
+
+pub fn column_status(index: usize) -> Status {
+    match index {
+        0 => Status::Todo,
+        1 => Status::InProgress,
+        _ => Status::Done,
+    }
+}
+
+fn priority_rank(priority: &Priority) -> u8 {
+    match priority {
+        Priority::Urgent => 0,
+        Priority::High => 1,
+        Priority::Medium => 2,
+        Priority::Low => 3,
+    }
+}
+
+// Tasks grouped by status: open columns by priority then due date, done by most recent
+pub fn board_columns(store: &TaskStore, project_id: Option<u32>) -> Result<[Vec<Task>; 3], Box<dyn std::error::Error>> {
+    let tasks = match project_id {
+        Some(project_id) => store.find_tasks_by_project(project_id)?,
+        None => store.list()?,
+    };
+
+    let now = Local::now().timestamp();
+    let mut columns: [Vec<Task>; 3] = Default::default();
+    for task in tasks.into_iter().filter(|t| !t.is_waiting(now)) {
+        columns[column_index(&task.status)].push(task);
+    }
+
+    for column in &mut columns[..2] {
+        column.sort_by_key(|t| (priority_rank(&t.priority), t.due_date.unwrap_or(i64::MAX), t.id));
+    }
+    columns[2].sort_by_key(|t| std::cmp::Reverse(t.completed_at.unwrap_or(t.updated_at)));
+
+    Ok(columns)
+}
+
+pub fn card_text(task: &Task) -> String {
+    match task.priority {
+        Priority::Urgent | Priority::High => format!("[{}] {} !{}", task.id, task.title, task.priority),
+        Priority::Medium | Priority::Low => format!("[{}] {}", task.id, task.title),
+    }
+}
+
+pub fn show_board(interactive: bool, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    if interactive {
+        return run_board(context);
+    }
+
+    let store = TaskStore::new()?;
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let columns = board_columns(&store, project_id)?;
+
+    let scope = match &context.current_project {
+        Some(project) => format!("project '{}'", project.name),
+        None => "all tasks".to_string(),
+    };
+    println!("🗂️  Board for {}:", scope);
+
+    let width = ratatui::crossterm::terminal::size().map(|(w, _)| w as usize).unwrap_or(100);
+    let cards: Vec<Vec<String>> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, tasks)| {
+            let mut cards: Vec<String> = tasks.iter().map(card_text).collect();
+            if i == 2 && cards.len() > DONE_LIMIT {
+                let hidden = cards.len() - DONE_LIMIT;
+                cards.truncate(DONE_LIMIT);
+                cards.push(format!("… {} more", hidden));
+            }
+            cards
+        })
+        .collect();
+    let titles: Vec<String> = columns
+        .iter()
+        .zip(COLUMN_TITLES)
+        .map(|(tasks, title)| format!("{} ({})", title, tasks.len()))
+        .collect();
+
+    for line in render_columns(&titles, &cards, width) {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+fn fit(text: &str, width: usize) -> String {
+    let count = text.chars().count();
+    if count <= width {
+        format!("{}{}", text, " ".repeat(width - count))
+    } else {
+        let truncated: String = text.chars().take(width.saturating_sub(1)).collect();
+        format!("{}…", truncated)
+    }
+}
+
+// Side-by-side boxed columns sized to the terminal width
+pub fn render_columns(titles: &[String], cards: &[Vec<String>], width: usize) -> Vec<String> {
+    let columns = titles.len().max(1);
+    let inner = ((width.saturating_sub(columns + 1)) / columns).clamp(12, 40);
+    let rule = "─".repeat(inner);
+
+    let border = |left: &str, middle: &str, right: &str| {
+        format!("{}{}{}", left, vec![rule.as_str(); columns].join(middle), right)
+    };
+    let row = |cells: Vec<String>| format!("│{}│", cells.join("│"));
+
+    let mut lines = vec![border("┌", "┬", "┐")];
+    lines.push(row(titles.iter().map(|t| fit(&format!(" {}", t), inner)).collect()));
+    lines.push(border("├", "┼", "┤"));
+
+    let height = cards.iter().map(Vec::len).max().unwrap_or(0).max(1);
+    for i in 0..height {
+        lines.push(row(cards
+            .iter()
+            .map(|column| fit(&column.get(i).map(|c| format!(" {}", c)).unwrap_or_default(), inner))
+            .collect()));
+    }
+
+    lines.push(border("└", "┴", "┘"));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_columns_pads_and_truncates() {
+        let titles = vec!["Todo (2)".to_string(), "Done (0)".to_string()];
+        let cards = vec![vec!["[1] Short".to_string(), "[2] A much longer card title".to_string()], vec![]];
+        let lines = render_columns(&titles, &cards, 31);
+
+        assert_eq!(lines.len(), 6);
+        assert!(lines.iter().all(|l| l.chars().count() == 31));
+        assert_eq!(lines[1], "│ Todo (2)     │ Done (0)     │");
+        assert_eq!(lines[4], "│ [2] A much l…│              │");
+    }
+}
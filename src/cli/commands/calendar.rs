@@ -0,0 +1,182 @@
+use crate::cli::format::format_day;
+use crate::config::{Config, WeekStart};
+use crate::repl::command_handler::ReplContext;
+use crate::task::dates::end_of_day_timestamp;
+use crate::task::{Task, TaskStore};
+use chrono::{Datelike, Duration, Local, Months, NaiveDate};
+use std::collections::BTreeMap;
+
+const MONTH_NAMES: [&str; 12] = [
+    "january", "february", "march", "april", "may", "june", "july", "august", "september", "october", "november",
+    "december",
+];
+
+pub fn show_calendar(month: Option<String>, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let today = Local::now().date_naive();
+    let this_month = first_of_month(today);
+
+    // 'next' and 'prev' step from the month shown last in this session
+    let base = context.calendar_month.get().unwrap_or(this_month);
+    let first = match month {
+        Some(month) => parse_month(&month, base, today)?,
+        None => this_month,
+    };
+    context.calendar_month.set(Some(first));
+
+    let last = first + Months::new(1) - Duration::days(1);
+    let store = TaskStore::new()?;
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let mut due_by_day: BTreeMap<NaiveDate, Vec<Task>> = BTreeMap::new();
+    for task in store.find_open_due_before(end_of_day_timestamp(last)?, project_id)? {
+        if let Some(due) = task.due_date_datetime().map(|d| d.date_naive())
+            && due >= first
+        {
+            due_by_day.entry(due).or_default().push(task);
+        }
+    }
+
+    let scope = match &context.current_project {
+        Some(project) => format!(" - {}", project.name),
+        None => String::new(),
+    };
+    println!("📅 {}{}", first.format("%B %Y"), scope);
+    println!();
+    let week_start = Config::current().display.week_start;
+    let mut weekday = week_start.weekday();
+    let mut names = Vec::new();
+    for _ in 0..7 {
+        names.push(format!("{:^7}", weekday.to_string()));
+        weekday = weekday.succ();
+    }
+    println!(" {}", names.join(" "));
+
+    for week in month_grid(first, week_start) {
+        let cells: Vec<String> = week
+            .iter()
+            .map(|day| match day {
+                Some(day) => day_cell(*day, due_by_day.get(day).map_or(0, Vec::len), today),
+                None => " ".repeat(7),
+            })
+            .collect();
+        println!(" {}", cells.join(" ").trim_end());
+    }
+
+    println!();
+    println!(" [dd] today   ·N due   !N overdue");
+
+    if !due_by_day.is_empty() {
+        println!();
+        for (day, tasks) in &due_by_day {
+            let titles: Vec<String> = tasks.iter().map(|t| format!("[{}] {}", t.id, t.title)).collect();
+            let marker = if *day < today { "⚠️ " } else { "  " };
+            println!("{} {}  {}", marker, format_day(*day), titles.join(", "));
+        }
+    }
+
+    Ok(())
+}
+
+// A 7-wide cell: the day (bracketed when it is today) and the number of open tasks due
+fn day_cell(day: NaiveDate, due: usize, today: NaiveDate) -> String {
+    let (open, close) = if day == today { ("[", "]") } else { (" ", " ") };
+    let count = match due {
+        0 => String::new(),
+        n if day < today => format!("!{}", n),
+        n => format!("·{}", n),
+    };
+    format!("{}{:>2}{}{:<3}", open, day.day(), close, count)
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).expect("every month has a first day")
+}
+
+// Weeks of the month starting on the configured day, with None for days outside it
+pub fn month_grid(first: NaiveDate, week_start: WeekStart) -> Vec<[Option<NaiveDate>; 7]> {
+    let mut weeks = Vec::new();
+    let mut week = [None; 7];
+    let mut day = first;
+    while day.month() == first.month() {
+        let weekday = day.weekday().days_since(week_start.weekday()) as usize;
+        week[weekday] = Some(day);
+        if weekday == 6 {
+            weeks.push(week);
+            week = [None; 7];
+        }
+        day += Duration::days(1);
+    }
+    if week.iter().any(Option::is_some) {
+        weeks.push(week);
+    }
+    weeks
+}
+
+// Accepts next/prev, YYYY-MM, a month number or a month name (this year)
+pub fn parse_month(input: &str, base: NaiveDate, today: NaiveDate) -> Result<NaiveDate, String> {
+    let input = input.trim().to_lowercase();
+    let invalid = || format!("Invalid month: {}. Use: next, prev, YYYY-MM, 1-12 or a month name", input);
+
+    match input.as_str() {
+        "next" | "+" | "+1" => return Ok(base + Months::new(1)),
+        "prev" | "previous" | "-" | "-1" => return Ok(base - Months::new(1)),
+        "today" | "now" => return Ok(first_of_month(today)),
+        _ => {}
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(&format!("{}-01", input), "%Y-%m-%d") {
+        return Ok(date);
+    }
+
+    let month = match input.parse::<u32>() {
+        Ok(month) => month,
+        Err(_) => match MONTH_NAMES.iter().position(|name| input.len() >= 3 && name.starts_with(input.as_str())) {
+            Some(index) => index as u32 + 1,
+            None => return Err(invalid()),
+        },
+    };
+    NaiveDate::from_ymd_opt(today.year(), month, 1).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_month() {
+        let today = date(2026, 10, 18);
+        let base = date(2026, 12, 1);
+        assert_eq!(parse_month("next", base, today), Ok(date(2027, 1, 1)));
+        assert_eq!(parse_month("prev", base, today), Ok(date(2026, 11, 1)));
+        assert_eq!(parse_month("2025-02", base, today), Ok(date(2025, 2, 1)));
+        assert_eq!(parse_month("3", base, today), Ok(date(2026, 3, 1)));
+        assert_eq!(parse_month("Sept", base, today), Ok(date(2026, 9, 1)));
+        assert!(parse_month("13", base, today).is_err());
+        assert!(parse_month("ma", base, today).is_err());
+    }
+
+    #[test]
+    fn test_month_grid_follows_week_start() {
+        // October 2026 starts on a Thursday and spans five weeks
+        let weeks = month_grid(date(2026, 10, 1), WeekStart::Monday);
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][3], Some(date(2026, 10, 1)));
+        assert_eq!(weeks[0][2], None);
+        assert_eq!(weeks[4][5], Some(date(2026, 10, 31)));
+
+        let weeks = month_grid(date(2026, 10, 1), WeekStart::Sunday);
+        assert_eq!(weeks[0][4], Some(date(2026, 10, 1)));
+        assert_eq!(weeks[4][6], Some(date(2026, 10, 31)));
+    }
+
+    #[test]
+    fn test_day_cell_marks_today_and_overdue() {
+        let today = date(2026, 10, 18);
+        assert_eq!(day_cell(today, 2, today), "[18]·2 ");
+        assert_eq!(day_cell(date(2026, 10, 3), 1, today), "  3 !1 ");
+        assert_eq!(day_cell(date(2026, 10, 20), 0, today), " 20    ");
+    }
+}
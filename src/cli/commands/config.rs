@@ -0,0 +1,192 @@
+use crate::config::Config;
+use std::fs;
+use toml::{Table, Value};
+use toml_edit::{DocumentMut, Item, TableLike};
+
+// Sections whose keys are free-form names rather than fixed settings
+const MAP_SECTIONS: [&str; 3] = ["aliases", "urgency.tags", "urgency.projects"];
+
+pub fn config_show() -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::load()?;
+    println!("⚙️  {}", Config::path().display());
+    println!();
+    print!("{}", toml::to_string_pretty(&config)?);
+    Ok(())
+}
+
+pub fn config_path() {
+    println!("{}", Config::path().display());
+}
+
+pub fn config_get(key: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let effective = Table::try_from(Config::load()?)?;
+    match lookup(&effective, key) {
+        Some(Value::String(s)) => println!("{}", s),
+        Some(Value::Table(table)) => print!("{}", toml::to_string_pretty(table)?),
+        Some(value) => println!("{}", value),
+        None if is_known_key(&effective, key) => println!("(not set)"),
+        None => println!("❌ Unknown setting: {}", key),
+    }
+    Ok(())
+}
+
+pub fn config_set(key: &str, value: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let effective = Table::try_from(Config::load()?)?;
+    if !is_known_key(&effective, key) {
+        println!("❌ Unknown setting: {}", key);
+        return Ok(());
+    }
+
+    let value = parse_value(value, lookup(&effective, key));
+    let mut file = read_file()?;
+    insert(file.as_table_mut(), key, &value)?;
+
+    // Check the result the same way loading does before writing it
+    let config: Config = toml::from_str(&file.to_string()).map_err(|e| format!("Invalid value for {}: {}", key, e))?;
+    config.validate()?;
+
+    write_file(&file)?;
+    println!("✅ {} = {}", key, value);
+    Ok(())
+}
+
+pub fn config_unset(key: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut file = read_file()?;
+    if remove(file.as_table_mut(), key).is_none() {
+        println!("{} is not set in {}; the default applies.", key, Config::path().display());
+        return Ok(());
+    }
+
+    write_file(&file)?;
+    println!("🗑️  {} removed; the default applies again.", key);
+    Ok(())
+}
+
+// The file as a document, so edits keep the user's comments and layout
+fn read_file() -> Result<DocumentMut, Box<dyn std::error::Error>> {
+    let path = Config::path();
+    if !path.exists() {
+        return Ok(DocumentMut::new());
+    }
+    let content = fs::read_to_string(&path)?;
+    content.parse::<DocumentMut>().map_err(|e| format!("Invalid config file {}: {}", path.display(), e).into())
+}
+
+fn write_file(document: &DocumentMut) -> Result<(), Box<dyn std::error::Error>> {
+    let path = Config::path();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, document.to_string())?;
+    Config::reload();
+    Ok(())
+}
+
+fn lookup<'a>(table: &'a Table, key: &str) -> Option<&'a Value> {
+    let (section, name) = match key.rsplit_once('.') {
+        Some((section, name)) => (lookup(table, section)?.as_table()?, name),
+        None => (table, key),
+    };
+    section.get(name)
+}
+
+// A key is known if it has a default, is an optional setting, or names an entry in a map section
+fn is_known_key(effective: &Table, key: &str) -> bool {
+    lookup(effective, key).is_some()
+        || key == "defaults.project"
+        || key.rsplit_once('.').is_some_and(|(section, _)| MAP_SECTIONS.contains(&section))
+}
+
+// Keep the type of the current value; anything else is read as a TOML value, falling back to text
+fn parse_value(input: &str, current: Option<&Value>) -> Value {
+    let parsed = format!("value = {}", input)
+        .parse::<Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"));
+
+    match (current, parsed) {
+        (Some(Value::String(_)) | None, Some(Value::String(s))) => Value::String(s),
+        (Some(Value::String(_)), _) => Value::String(input.to_string()),
+        (Some(Value::Float(_)), Some(Value::Integer(n))) => Value::Float(n as f64),
+        (_, Some(value)) => value,
+        (_, None) => Value::String(input.to_string()),
+    }
+}
+
+fn insert(table: &mut dyn TableLike, key: &str, value: &Value) -> Result<(), String> {
+    match key.split_once('.') {
+        Some((section, rest)) => {
+            if table.get(section).is_none() {
+                let mut new_section = toml_edit::Table::new();
+                new_section.set_implicit(true);
+                table.insert(section, Item::Table(new_section));
+            }
+            match table.get_mut(section).and_then(Item::as_table_like_mut) {
+                Some(section) => insert(section, rest, value),
+                None => Err(format!("{} is not a section", section)),
+            }
+        }
+        None => {
+            let mut new_value = value
+                .to_string()
+                .parse::<toml_edit::Value>()
+                .map_err(|e| format!("Invalid value for {}: {}", key, e))?;
+            // Replace in place so comments around the existing line stay
+            match table.get_mut(key) {
+                Some(item) => {
+                    if let Some(old) = item.as_value() {
+                        *new_value.decor_mut() = old.decor().clone();
+                    }
+                    *item = Item::Value(new_value);
+                }
+                None => {
+                    table.insert(key, Item::Value(new_value));
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn remove(table: &mut dyn TableLike, key: &str) -> Option<Item> {
+    match key.split_once('.') {
+        Some((section, rest)) => remove(table.get_mut(section)?.as_table_like_mut()?, rest),
+        None => table.remove(key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_value_keeps_current_type() {
+        let text = Value::String("medium".to_string());
+        assert_eq!(parse_value("high", Some(&text)), Value::String("high".to_string()));
+        assert_eq!(parse_value("42", Some(&text)), Value::String("42".to_string()));
+        assert_eq!(parse_value("20", Some(&Value::Float(12.0))), Value::Float(20.0));
+        assert_eq!(parse_value("false", Some(&Value::Boolean(true))), Value::Boolean(false));
+        assert_eq!(parse_value("done", None), Value::String("done".to_string()));
+    }
+
+    #[test]
+    fn test_insert_and_lookup_dotted_keys() {
+        let mut document = DocumentMut::new();
+        insert(document.as_table_mut(), "display.date_format", &Value::String("%d.%m.%Y".to_string())).unwrap();
+        insert(document.as_table_mut(), "urgency.tags.next", &Value::Float(20.0)).unwrap();
+        let table: Table = document.to_string().parse().unwrap();
+        assert_eq!(lookup(&table, "display.date_format").and_then(Value::as_str), Some("%d.%m.%Y"));
+        assert_eq!(lookup(&table, "urgency.tags.next"), Some(&Value::Float(20.0)));
+
+        assert!(remove(document.as_table_mut(), "urgency.tags.next").is_some());
+        let table: Table = document.to_string().parse().unwrap();
+        assert!(lookup(&table, "urgency.tags.next").is_none());
+    }
+
+    #[test]
+    fn test_insert_keeps_comments() {
+        let mut document: DocumentMut = "# my settings\n[display]\n# server\nglyphs = \"emoji\" # for now\n".parse().unwrap();
+        insert(document.as_table_mut(), "display.glyphs", &Value::String("ascii".to_string())).unwrap();
+        assert_eq!(document.to_string(), "# my settings\n[display]\n# server\nglyphs = \"ascii\" # for now\n");
+    }
+}
@@ -1,21 +1,30 @@
+use crate::cli::target::{confirm_bulk, parse_whole_target, resolve_target};
+use crate::repl::command_handler::ReplContext;
 use crate::task::TaskStore;
 
-pub fn done_task(id: u32) -> Result<(), Box<dyn std::error::Error>> {
+pub fn done_task(target: &[String], dry_run: bool, yes: bool, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
     let store = TaskStore::new()?;
-    
-    // Check if task exists
-    let task = match store.find_by_id(id)? {
-        Some(task) => task,
-        None => {
-            println!("❌ Task with ID {} not found.", id);
-            return Ok(());
+    let target = parse_whole_target(target)?;
+    let tasks = resolve_target(&store, &target, context)?;
+
+    if dry_run {
+        for task in &tasks {
+            println!("Would mark task {} as done: '{}'", task.id, task.title);
         }
-    };
-    
+        return Ok(());
+    }
+    if !confirm_bulk("complete", tasks.iter().map(|t| (t.id, t.title.as_str())), yes, context)? {
+        return Ok(());
+    }
+
     // Update task status to Done
-    store.update_status(id, crate::task::Status::Done)?;
-    
-    println!("✅ Task {} marked as done: '{}'", id, task.title);
-    
+    for task in &tasks {
+        store.update_status(task.id, crate::task::Status::Done)?;
+        println!("✅ Task {} marked as done: '{}'", task.id, task.title);
+    }
+    if tasks.len() > 1 {
+        println!("{} tasks marked as done.", tasks.len());
+    }
+
     Ok(())
-}
\ No newline at end of file
+}
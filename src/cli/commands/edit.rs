@@ -0,0 +1,292 @@
+use crate::cli::commands::add::target_project;
+use crate::cli::commands::list::find_matching_tasks;
+use crate::cli::commands::update::keep_identity;
+use crate::cli::filter::parse_filter;
+use crate::cli::target::confirm_bulk;
+use crate::config::Config;
+use crate::editor::edit_until_valid;
+use crate::project::{Project, ProjectStore};
+use crate::repl::command_handler::ReplContext;
+use crate::task::template::{bulk_template, TaskFields, TaskList};
+use crate::task::{Status, Task, TaskDraft, TaskStore};
+use std::collections::HashSet;
+
+// What saving a bulk edit will do to the database
+#[derive(Debug, Default)]
+struct BulkChanges {
+    updated: Vec<Task>,
+    removed: Vec<(u32, String)>,
+    added: Vec<Task>,
+}
+
+pub fn edit_bulk(filter: &[String], markdown: bool, yes: bool, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let projects = ProjectStore::new()?.list()?;
+    let filter = parse_filter(&filter.join(" "))?;
+    let tasks = find_matching_tasks(&store, &filter, context)?;
+
+    if tasks.is_empty() {
+        println!("No tasks found.");
+        return Ok(());
+    }
+
+    // New entries start from the configured defaults; checklist items also go to the target project
+    let mut new_task = TaskDraft::from_defaults(&Config::current().defaults);
+    let changes = if markdown {
+        new_task.project_id = target_project(context)?.map(|(id, _)| id);
+        edit_until_valid(&checklist_template(&tasks), |content| parse_checklist(content, &tasks, &new_task))?
+    } else {
+        edit_until_valid(&bulk_template(&tasks, &projects)?, |content| {
+            parse_task_list(content, &tasks, &projects, &new_task)
+        })?
+    };
+
+    let changes = match changes {
+        Some(changes) => changes,
+        None => {
+            println!("❌ Cancelled: the file was left empty. No tasks were changed.");
+            return Ok(());
+        }
+    };
+
+    if changes.updated.is_empty() && changes.removed.is_empty() && changes.added.is_empty() {
+        println!("No changes.");
+        return Ok(());
+    }
+
+    println!(
+        "Saving will update {}, remove {} and add {} task(s).",
+        changes.updated.len(),
+        changes.removed.len(),
+        changes.added.len()
+    );
+    // Deleting entries is easy to do by accident in an editor
+    let removed = changes.removed.iter().map(|(id, title)| (*id, title.as_str()));
+    if !confirm_bulk("remove", removed, yes, context)? {
+        return Ok(());
+    }
+
+    let removed_ids: Vec<u32> = changes.removed.iter().map(|(id, _)| *id).collect();
+    let added_ids = store.apply_changes(&changes.updated, &removed_ids, &changes.added)?;
+
+    for task in &changes.updated {
+        println!("✏️  Task {} updated: '{}'", task.id, task.title);
+    }
+    for (id, title) in &changes.removed {
+        println!("🗑️  Task {} removed: '{}'", id, title);
+    }
+    for (id, task) in added_ids.iter().zip(&changes.added) {
+        println!("➕ Task {} added: '{}'", id, task.title);
+    }
+    println!(
+        "✅ Bulk edit saved: {} updated, {} removed, {} added.",
+        changes.updated.len(),
+        changes.removed.len(),
+        changes.added.len()
+    );
+
+    Ok(())
+}
+
+fn parse_task_list(
+    content: &str,
+    originals: &[Task],
+    projects: &[Project],
+    new_task: &TaskDraft,
+) -> Result<BulkChanges, String> {
+    let list: TaskList = toml::from_str(content).map_err(|e| format!("Invalid TOML: {}", e))?;
+    let mut changes = BulkChanges::default();
+    let mut seen = HashSet::new();
+
+    for (index, mut fields) in list.task.into_iter().enumerate() {
+        let entry = index + 1;
+        let original = match fields.id {
+            Some(id) => Some(find_original(id, originals, &mut seen)?),
+            None => None,
+        };
+        if original.is_none() {
+            if fields.status.trim().is_empty() {
+                fields.status = new_task.status.clone();
+            }
+            if fields.priority.trim().is_empty() {
+                fields.priority = new_task.priority.clone();
+            }
+        }
+
+        // Leave entries that were saved as they were written out alone
+        if let Some(original) = original
+            && fields == (TaskFields { id: Some(original.id), ..TaskFields::from_draft(&original.to_draft(), projects) })
+        {
+            continue;
+        }
+
+        let mut task = fields
+            .into_draft(projects)
+            .and_then(TaskDraft::into_task)
+            .map_err(|e| format!("[[task]] entry {}: {}", entry, e))?;
+        match original {
+            Some(original) => {
+                keep_identity(&mut task, original);
+                changes.updated.push(task);
+            }
+            None => changes.added.push(task),
+        }
+    }
+
+    changes.removed = removed_tasks(originals, &seen);
+    Ok(changes)
+}
+
+// '- [ ] #3 Title' for existing tasks; the box is ' ' for todo, '~' for in progress and 'x' for done
+fn checklist_template(tasks: &[Task]) -> String {
+    let mut content = [
+        "# Save to apply, or delete everything to cancel. Lines starting with '#' are ignored.",
+        "# Check a box with 'x' for done or '~' for in progress, and edit titles in place.",
+        "# Remove a line to delete that task, or add '- [ ] Title' to create one.",
+    ]
+    .join("\n");
+    content.push_str("\n\n");
+
+    for task in tasks {
+        content.push_str(&format!("- [{}] #{} {}\n", status_box(&task.status), task.id, task.title));
+    }
+    content
+}
+
+fn status_box(status: &Status) -> char {
+    match status {
+        Status::Todo => ' ',
+        Status::InProgress => '~',
+        Status::Done => 'x',
+    }
+}
+
+fn parse_checklist(content: &str, originals: &[Task], new_task: &TaskDraft) -> Result<BulkChanges, String> {
+    let mut changes = BulkChanges::default();
+    let mut seen = HashSet::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (status, id, title) = parse_checklist_line(line).map_err(|e| format!("Line {}: {}", index + 1, e))?;
+
+        let draft = match id {
+            Some(id) => {
+                let original = find_original(id, originals, &mut seen)?;
+                if original.title == title && original.status.to_string() == status {
+                    continue;
+                }
+                TaskDraft { title, status, ..original.to_draft() }
+            }
+            None => TaskDraft {
+                title,
+                status,
+                priority: new_task.priority.clone(),
+                project_id: new_task.project_id,
+                ..TaskDraft::new()
+            },
+        };
+
+        let mut task = draft.into_task().map_err(|e| format!("Line {}: {}", index + 1, e))?;
+        match id.and_then(|id| originals.iter().find(|t| t.id == id)) {
+            Some(original) => {
+                keep_identity(&mut task, original);
+                changes.updated.push(task);
+            }
+            None => changes.added.push(task),
+        }
+    }
+
+    changes.removed = removed_tasks(originals, &seen);
+    Ok(changes)
+}
+
+// Returns the status name, the task ID if the line has one, and the title
+fn parse_checklist_line(line: &str) -> Result<(String, Option<u32>, String), String> {
+    let usage = || format!("expected '- [ ] Title' or '- [x] #<id> Title', found '{}'", line);
+    let rest = line.strip_prefix("- [").ok_or_else(usage)?;
+    let mut chars = rest.chars();
+    let status = match chars.next() {
+        Some(' ') => "todo",
+        Some('~') => "in_progress",
+        Some('x' | 'X') => "done",
+        _ => return Err(usage()),
+    };
+    let rest = chars.as_str().strip_prefix(']').ok_or_else(usage)?.trim();
+
+    // A bare '#3' is the ID with its title cleared, which the task then rejects
+    let id_and_title = rest.strip_prefix('#').map(|r| r.split_once(char::is_whitespace).unwrap_or((r, "")));
+    let (id, title) = match id_and_title {
+        Some((id, title)) if id.parse::<u32>().is_ok() => (id.parse().ok(), title.trim()),
+        _ => (None, rest),
+    };
+    Ok((status.to_string(), id, title.to_string()))
+}
+
+fn find_original<'a>(id: u32, originals: &'a [Task], seen: &mut HashSet<u32>) -> Result<&'a Task, String> {
+    let original = originals
+        .iter()
+        .find(|task| task.id == id)
+        .ok_or_else(|| format!("Task {} is not part of this edit. Remove its id to add it as a new task", id))?;
+    if !seen.insert(id) {
+        return Err(format!("Task {} appears more than once", id));
+    }
+    Ok(original)
+}
+
+fn removed_tasks(originals: &[Task], seen: &HashSet<u32>) -> Vec<(u32, String)> {
+    originals
+        .iter()
+        .filter(|task| !seen.contains(&task.id))
+        .map(|task| (task.id, task.title.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: u32, title: &str) -> Task {
+        let mut task = TaskDraft { title: title.to_string(), ..TaskDraft::new() }.into_task().unwrap();
+        task.id = id;
+        task
+    }
+
+    #[test]
+    fn test_parse_task_list_diff() {
+        let originals = vec![task(1, "Keep"), task(2, "Rename"), task(3, "Drop")];
+        let content = bulk_template(&originals, &[])
+            .unwrap()
+            .replace("title = \"Rename\"", "title = \"Renamed\"")
+            .replace("id = 3\ntitle = \"Drop\"", "title = \"Brand new\"");
+
+        let changes = parse_task_list(&content, &originals, &[], &TaskDraft::new()).unwrap();
+        assert_eq!(changes.updated.iter().map(|t| (t.id, t.title.as_str())).collect::<Vec<_>>(), vec![(2, "Renamed")]);
+        assert_eq!(changes.removed, vec![(3, "Drop".to_string())]);
+        assert_eq!(changes.added.iter().map(|t| t.title.as_str()).collect::<Vec<_>>(), vec!["Brand new"]);
+    }
+
+    #[test]
+    fn test_parse_task_list_rejects_unknown_and_duplicate_ids() {
+        let originals = vec![task(1, "Keep")];
+        assert!(parse_task_list("[[task]]\nid = 9\ntitle = \"Other\"\n", &originals, &[], &TaskDraft::new()).is_err());
+        assert!(parse_task_list("[[task]]\nid = 1\ntitle = \"A\"\n[[task]]\nid = 1\ntitle = \"B\"\n", &originals, &[], &TaskDraft::new()).is_err());
+    }
+
+    #[test]
+    fn test_parse_checklist() {
+        let originals = vec![task(1, "Write"), task(2, "Review")];
+        let content = checklist_template(&originals).replace("- [ ] #1 Write", "- [x] #1 Write").replace("- [ ] #2 Review\n", "- [ ] Plan next sprint\n");
+
+        let changes = parse_checklist(&content, &originals, &TaskDraft::new()).unwrap();
+        assert_eq!(changes.updated.len(), 1);
+        assert!(matches!(changes.updated[0].status, Status::Done));
+        assert_eq!(changes.removed, vec![(2, "Review".to_string())]);
+        assert_eq!(changes.added[0].title, "Plan next sprint");
+
+        assert!(parse_checklist("* Review\n", &originals, &TaskDraft::new()).is_err());
+        assert!(parse_checklist("- [x] #1\n- [ ] #2 Review\n", &originals, &TaskDraft::new()).is_err());
+    }
+}
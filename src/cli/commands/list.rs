@@ -1,79 +1,288 @@
+use crate::cli::commands::next::load_urgency_scorer;
+use crate::cli::filter::{parse_filter, SortKey, TaskFilter};
+use crate::cli::format::{format_date, status_emoji};
+use crate::cli::style::{paint, Theme};
+use crate::config::{Config, Glyphs};
 use crate::project::ProjectStore;
 use crate::repl::command_handler::ReplContext;
-use crate::task::{Task, TaskStore};
-use std::collections::HashMap;
-
-fn get_task_display_info(task: &Task, project_map: &HashMap<u32, String>, show_project: bool) -> (String, String, String) {
-    let status_emoji = match task.status {
-        crate::task::Status::Todo => "⏳",
-        crate::task::Status::InProgress => "🔄",
-        crate::task::Status::Done => "✅",
-    };
-    
-    let project_info = if show_project {
-        match task.project_id.and_then(|id| project_map.get(&id)) {
-            Some(project_name) => format!(" @{}", project_name),
-            None => "".to_string(),
+use crate::task::duration::format_minutes;
+use crate::task::tags::tag_value;
+use crate::task::{Status, Task, TaskStore};
+use crate::timelog::TimeStore;
+use chrono::Local;
+use std::collections::{BTreeMap, HashMap};
+
+// Everything needed to render a single task line, loaded once per listing
+pub struct TaskLineFormat {
+    pub project_map: HashMap<u32, String>,
+    pub show_project: bool,
+    pub tracked_seconds: HashMap<u32, i64>,
+    // Filled in when listing by urgency
+    pub urgency: HashMap<u32, f64>,
+    pub theme: Theme,
+    pub glyphs: Glyphs,
+}
+
+impl TaskLineFormat {
+    pub fn load(context: &ReplContext) -> Result<Self, Box<dyn std::error::Error>> {
+        let project_store = ProjectStore::new()?;
+        let time_store = TimeStore::new()?;
+
+        // Create a map of project_id -> project_name for display
+        let projects = project_store.list()?;
+        let project_map: HashMap<u32, String> = projects.into_iter().map(|p| (p.id, p.name)).collect();
+
+        Ok(TaskLineFormat {
+            project_map,
+            show_project: context.current_project.is_none(),
+            tracked_seconds: time_store.totals_by_task(Local::now().timestamp())?,
+            urgency: HashMap::new(),
+            theme: Theme::load(),
+            glyphs: Config::current().display.glyphs,
+        })
+    }
+
+    fn get_task_display_info(&self, task: &Task) -> (String, String, String, String) {
+        let status_emoji = paint(status_emoji(&task.status), self.theme.status(&task.status));
+
+        let project_info = if self.show_project {
+            match task.project_id.and_then(|id| self.project_map.get(&id)) {
+                Some(project_name) => format!(" {}", paint(&format!("@{}", project_name), &self.theme.project)),
+                None => "".to_string(),
+            }
+        } else {
+            "".to_string()
+        };
+
+        let priority_info = match task.priority {
+            crate::task::model::Priority::Low => "!low",
+            crate::task::model::Priority::Medium => "!medium",
+            crate::task::model::Priority::High => "!high",
+            crate::task::model::Priority::Urgent => "!urgent",
+        };
+        let priority_info = format!(" {}", paint(priority_info, self.theme.priority(&task.priority)));
+
+        // Tracked time is shown against the estimate when there is one: ⏱ 1h15m/2h
+        let timer = self.glyph("⏱ ", "time:");
+        let tracked_minutes = self.tracked_seconds.get(&task.id).map(|s| s / 60).filter(|m| *m > 0);
+        let time_info = match (tracked_minutes, task.estimate_minutes) {
+            (Some(tracked), Some(estimate)) => {
+                format!(" {}{}/{}", timer, format_minutes(tracked), format_minutes(estimate as i64))
+            }
+            (Some(tracked), None) => format!(" {}{}", timer, format_minutes(tracked)),
+            (None, Some(estimate)) => format!(" {}0m/{}", timer, format_minutes(estimate as i64)),
+            (None, None) => "".to_string(),
+        };
+
+        (status_emoji, project_info, priority_info, time_info)
+    }
+
+    // Open tasks show their due date, styled as overdue once it has passed
+    fn due_info(&self, task: &Task) -> String {
+        let due = match task.due_date_datetime() {
+            Some(due) if !matches!(task.status, Status::Done) => due,
+            _ => return String::new(),
+        };
+        let style = if due < Local::now() { &self.theme.overdue } else { &self.theme.due };
+        let text = format!("{}{}", self.glyph("📅 ", "due:"), format_date(due.date_naive()));
+        format!(" {}", paint(&text, style))
+    }
+
+    pub fn glyph(&self, emoji: &'static str, ascii: &'static str) -> &'static str {
+        match self.glyphs {
+            Glyphs::Emoji => emoji,
+            Glyphs::Ascii => ascii,
         }
-    } else {
-        "".to_string()
-    };
-    
-    let priority_info = match task.priority {
-        crate::task::model::Priority::Low => " !low",
-        crate::task::model::Priority::Medium => " !medium",
-        crate::task::model::Priority::High => " !high",
-        crate::task::model::Priority::Urgent => " !urgent",
-    };
-    
-    (status_emoji.to_string(), project_info, priority_info.to_string())
+    }
+
+    pub fn format(&self, task: &Task) -> String {
+        let (status_emoji, project_info, priority_info, time_info) = self.get_task_display_info(task);
+        let urgency_info = match self.urgency.get(&task.id) {
+            Some(score) => format!(" {}{:.1}", self.glyph("⚡", "urgency:"), score),
+            None => "".to_string(),
+        };
+        format!(
+            "{} [{}] {}{}{}{}{}{}",
+            status_emoji,
+            task.id,
+            task.title,
+            priority_info,
+            self.due_info(task),
+            time_info,
+            urgency_info,
+            project_info
+        )
+    }
 }
 
-pub fn list_tasks(context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+pub fn list_tasks(
+    filter: &[String],
+    include_waiting: bool,
+    sort: Option<SortKey>,
+    context: &ReplContext,
+) -> Result<(), Box<dyn std::error::Error>> {
     let store = TaskStore::new()?;
-    let project_store = ProjectStore::new()?;
+    let mut line_format = TaskLineFormat::load(context)?;
 
-    // Create a map of project_id -> project_name for display
-    let projects = project_store.list()?;
-    let project_map: HashMap<u32, String> = projects.into_iter().map(|p| (p.id, p.name)).collect();
+    let mut filter = parse_filter(&filter.join(" "))?;
+    if sort.is_some() {
+        filter.sort = sort;
+    }
+    if !filter.is_empty() {
+        return list_filtered_tasks(&store, &filter, include_waiting, &mut line_format, context);
+    }
 
-    let root_tasks = match &context.current_project {
+    let mut root_tasks = match &context.current_project {
         Some(project) => {
-            println!("📋 Tasks in project '{}':", project.name);
+            println!("{}Tasks in project '{}':", line_format.glyph("📋 ", ""), project.name);
             store.find_root_tasks_by_project(project.id)?
         }
         None => {
-            println!("📋 All tasks:");
+            println!("{}All tasks:", line_format.glyph("📋 ", ""));
             store.find_root_tasks()?
         }
     };
 
+    let mut waiting = hide_waiting(&mut root_tasks, include_waiting);
+
     if root_tasks.is_empty() {
         println!("No tasks found.");
-        return Ok(());
     }
 
     for root_task in root_tasks {
-        print_task_tree(
-            &store,
-            &root_task,
-            0,
-            &project_map,
-            context.current_project.is_none(),
-        )?;
+        waiting += print_task_tree(&store, &root_task, 0, &line_format, include_waiting)?;
     }
 
+    print_waiting_hint(waiting);
+
     Ok(())
 }
 
+// Drop tasks scheduled for later unless requested, returning how many were hidden
+fn hide_waiting(tasks: &mut Vec<Task>, include_waiting: bool) -> usize {
+    if include_waiting {
+        return 0;
+    }
+    let now = Local::now().timestamp();
+    let before = tasks.len();
+    tasks.retain(|task| !task.is_waiting(now));
+    before - tasks.len()
+}
+
+fn print_waiting_hint(waiting: usize) {
+    if waiting > 0 {
+        println!("({} scheduled task(s) hidden until their start date; use 'list --all' to show)", waiting);
+    }
+}
+
+// Tasks in the current project, or all tasks, that pass the filter
+pub fn find_matching_tasks(
+    store: &TaskStore,
+    filter: &TaskFilter,
+    context: &ReplContext,
+) -> Result<Vec<Task>, Box<dyn std::error::Error>> {
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let mut tasks = match (filter.tags.is_empty(), project_id) {
+        (false, _) => store.find_by_tags(&filter.tags, project_id)?,
+        (true, Some(project_id)) => store.find_tasks_by_project(project_id)?,
+        (true, None) => store.list()?,
+    };
+    tasks.retain(|task| filter.matches(task));
+    Ok(tasks)
+}
+
+fn list_filtered_tasks(
+    store: &TaskStore,
+    filter: &TaskFilter,
+    include_waiting: bool,
+    line_format: &mut TaskLineFormat,
+    context: &ReplContext,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut tasks = find_matching_tasks(store, filter, context)?;
+    let waiting = hide_waiting(&mut tasks, include_waiting);
+
+    if let Some(SortKey::Urgency) = filter.sort {
+        // Done tasks have no urgency and go last
+        let scorer = load_urgency_scorer(store)?;
+        line_format.urgency = tasks
+            .iter()
+            .filter(|task| !matches!(task.status, Status::Done))
+            .map(|task| (task.id, scorer.score(task)))
+            .collect();
+        let urgency = &line_format.urgency;
+        let key = |task: &Task| urgency.get(&task.id).copied().unwrap_or(f64::NEG_INFINITY);
+        tasks.sort_by(|a, b| key(b).total_cmp(&key(a)));
+    }
+
+    let scope = match &context.current_project {
+        Some(project) => format!("Tasks in project '{}'", project.name),
+        None => "Tasks".to_string(),
+    };
+    let icon = line_format.glyph("📋 ", "");
+    if filter.tags.is_empty() {
+        println!("{}{}:", icon, scope);
+    } else {
+        println!("{}{} tagged {}:", icon, scope, filter.tags.join(", "));
+    }
+
+    if tasks.is_empty() {
+        println!("No tasks found.");
+    }
+
+    match &filter.group_by {
+        Some(key) => print_grouped_tasks(&tasks, key, line_format),
+        None => {
+            for task in &tasks {
+                println!("{}", line_format.format(task));
+            }
+        }
+    }
+
+    print_waiting_hint(waiting);
+
+    Ok(())
+}
+
+fn print_grouped_tasks(tasks: &[Task], key: &str, line_format: &TaskLineFormat) {
+    // A task appears under every value it carries for the key
+    let mut groups: BTreeMap<&str, Vec<&Task>> = BTreeMap::new();
+    let mut ungrouped = Vec::new();
+    for task in tasks {
+        let values: Vec<&str> = task.tags.iter().filter_map(|t| tag_value(t, key)).collect();
+        if values.is_empty() {
+            ungrouped.push(task);
+        }
+        for value in values {
+            groups.entry(value).or_default().push(task);
+        }
+    }
+
+    for (value, tasks) in groups {
+        println!();
+        println!("{}:{} ({})", key, value, tasks.len());
+        for task in tasks {
+            println!("  {}", line_format.format(task));
+        }
+    }
+
+    if !ungrouped.is_empty() {
+        println!();
+        println!("(no {}) ({})", key, ungrouped.len());
+        for task in ungrouped {
+            println!("  {}", line_format.format(task));
+        }
+    }
+}
+
+// Each of these returns how many scheduled subtasks it hid
 fn print_task_tree(
     store: &TaskStore,
     task: &Task,
     indent_level: usize,
-    project_map: &HashMap<u32, String>,
-    show_project: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
-    print_task_tree_with_prefix(store, task, indent_level, false, project_map, show_project)
+    line_format: &TaskLineFormat,
+    include_waiting: bool,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    print_task_tree_with_prefix(store, task, indent_level, false, line_format, include_waiting)
 }
 
 fn print_task_tree_with_prefix(
@@ -81,34 +290,34 @@ fn print_task_tree_with_prefix(
     task: &Task,
     indent_level: usize,
     is_last: bool,
-    project_map: &HashMap<u32, String>,
-    show_project: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let (status_emoji, project_info, priority_info) = get_task_display_info(task, project_map, show_project);
-    
+    line_format: &TaskLineFormat,
+    include_waiting: bool,
+) -> Result<usize, Box<dyn std::error::Error>> {
     // Create proper tree indentation and characters
-    let (indent, tree_char) = build_tree_prefix(indent_level, is_last);
+    let (indent, tree_char) = build_tree_prefix(indent_level, is_last, line_format.glyphs);
 
-    println!(
-        "{}{}{}[{}] {}{}{}",
-        indent, tree_char, status_emoji, task.id, task.title, priority_info, project_info
-    );
+    println!("{}{}{}", indent, tree_char, line_format.format(task));
 
     // Recursively print children
-    print_task_children(store, task, indent_level, project_map, show_project)
+    print_task_children(store, task, indent_level, line_format, include_waiting)
 }
 
-fn build_tree_prefix(indent_level: usize, is_last: bool) -> (String, &'static str) {
+fn build_tree_prefix(indent_level: usize, is_last: bool, glyphs: Glyphs) -> (String, &'static str) {
     if indent_level == 0 {
         return (String::new(), "");
     }
-    
+
+    let (pipe, last, branch) = match glyphs {
+        Glyphs::Emoji => ("│  ", "└─ ", "├─ "),
+        Glyphs::Ascii => ("|  ", "`- ", "|- "),
+    };
+
     let mut indent = String::new();
     for _ in 1..indent_level {
-        indent.push_str("│  ");
+        indent.push_str(pipe);
     }
-    
-    let tree_char = if is_last { "└─ " } else { "├─ " };
+
+    let tree_char = if is_last { last } else { branch };
     (indent, tree_char)
 }
 
@@ -116,20 +325,21 @@ fn print_task_children(
     store: &TaskStore,
     task: &Task,
     indent_level: usize,
-    project_map: &HashMap<u32, String>,
-    show_project: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let children = store.find_children(task.id)?;
+    line_format: &TaskLineFormat,
+    include_waiting: bool,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let mut children = store.find_children(task.id)?;
+    let mut waiting = hide_waiting(&mut children, include_waiting);
     for (i, child) in children.iter().enumerate() {
         let is_last = i == children.len() - 1;
-        print_task_tree_with_prefix(
+        waiting += print_task_tree_with_prefix(
             store,
             child,
             indent_level + 1,
             is_last,
-            project_map,
-            show_project,
+            line_format,
+            include_waiting,
         )?;
     }
-    Ok(())
+    Ok(waiting)
 }
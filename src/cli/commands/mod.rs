@@ -1,11 +1,39 @@
 pub mod add;
+pub mod agenda;
+pub mod board;
+pub mod calendar;
+pub mod config;
 pub mod done;
+pub mod edit;
 pub mod update;
 pub mod list;
 pub mod remove;
+pub mod report;
+pub mod next;
+pub mod note;
+pub mod show;
+pub mod stats;
+pub mod tags;
+pub mod timesheet;
+pub mod track;
+pub mod view;
 
 pub use add::add_task;
+pub use agenda::{agenda_overdue, agenda_today, agenda_upcoming};
 pub use done::done_task;
+pub use edit::edit_bulk;
 pub use update::update_task;
+pub use board::show_board;
+pub use calendar::show_calendar;
+pub use config::{config_get, config_path, config_set, config_show, config_unset};
 pub use list::list_tasks;
-pub use remove::remove_task;
\ No newline at end of file
+pub use next::next_task;
+pub use remove::remove_task;
+pub use note::note_task;
+pub use show::show_task;
+pub use tags::{list_tags, merge_tags, rename_tag};
+pub use report::{report_burndown, report_flow};
+pub use stats::stats;
+pub use timesheet::timesheet;
+pub use track::{track_log, track_start, track_status, track_stop};
+pub use view::{delete_view, list_views, run_view, save_view};
\ No newline at end of file
@@ -0,0 +1,50 @@
+use crate::cli::commands::list::TaskLineFormat;
+use crate::config::Config;
+use crate::project::ProjectStore;
+use crate::repl::command_handler::ReplContext;
+use crate::task::urgency::UrgencyScorer;
+use crate::task::TaskStore;
+use chrono::Local;
+
+pub fn load_urgency_scorer(store: &TaskStore) -> Result<UrgencyScorer, Box<dyn std::error::Error>> {
+    let config = Config::current();
+    let project_names = ProjectStore::new()?.list()?.into_iter().map(|p| (p.id, p.name)).collect();
+    Ok(UrgencyScorer::new(config.urgency.clone(), &store.list()?, project_names, Local::now().timestamp()))
+}
+
+pub fn next_task(context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let scorer = load_urgency_scorer(&store)?;
+
+    let tasks = match &context.current_project {
+        Some(project) => store.find_tasks_by_project(project.id)?,
+        None => store.list()?,
+    };
+
+    let next = tasks
+        .iter()
+        .filter(|task| scorer.is_actionable(task))
+        .map(|task| (task, scorer.score(task)))
+        .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.id.cmp(&a.0.id)));
+
+    let (task, score) = match next {
+        Some(next) => next,
+        None => {
+            println!("🎉 Nothing actionable right now.");
+            return Ok(());
+        }
+    };
+
+    let line_format = TaskLineFormat::load(context)?;
+    println!("👉 Next: {}", line_format.format(task));
+
+    let terms: Vec<String> = scorer
+        .terms(task)
+        .iter()
+        .filter(|(_, value)| value.abs() >= 0.05)
+        .map(|(name, value)| format!("{} {:+.1}", name, value))
+        .collect();
+    println!("   Urgency {:.1}: {}", score, terms.join(", "));
+
+    Ok(())
+}
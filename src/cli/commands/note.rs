@@ -0,0 +1,25 @@
+use crate::repl::command_handler::ReplContext;
+use crate::task::TaskStore;
+use chrono::Local;
+
+pub fn note_task(id: u32, text: &str, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    if text.trim().is_empty() {
+        return Err("Note text cannot be empty".into());
+    }
+
+    let store = TaskStore::new()?;
+
+    // Check if task exists
+    let mut task = match store.find_by_id(id)? {
+        Some(task) => task,
+        None => return context.report(format!("Task with ID {} not found.", id)),
+    };
+
+    task.append_note(text);
+    task.updated_at = Local::now().timestamp();
+    store.update(&task)?;
+
+    println!("📝 Note added to task {}: '{}'", id, task.title);
+
+    Ok(())
+}
@@ -1,21 +1,30 @@
+use crate::cli::target::{confirm_bulk, parse_whole_target, resolve_target};
+use crate::repl::command_handler::ReplContext;
 use crate::task::TaskStore;
 
-pub fn remove_task(id: u32) -> Result<(), Box<dyn std::error::Error>> {
+pub fn remove_task(target: &[String], dry_run: bool, yes: bool, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
     let store = TaskStore::new()?;
-    
-    // Check if task exists
-    let task = match store.find_by_id(id)? {
-        Some(task) => task,
-        None => {
-            println!("❌ Task with ID {} not found.", id);
-            return Ok(());
+    let target = parse_whole_target(target)?;
+    let tasks = resolve_target(&store, &target, context)?;
+
+    if dry_run {
+        for task in &tasks {
+            println!("Would remove task {}: '{}'", task.id, task.title);
         }
-    };
-    
-    // Delete the task
-    store.delete(id)?;
-    
-    println!("🗑️  Task {} removed: '{}'", id, task.title);
-    
+        return Ok(());
+    }
+    if !confirm_bulk("remove", tasks.iter().map(|t| (t.id, t.title.as_str())), yes, context)? {
+        return Ok(());
+    }
+
+    // Delete the tasks
+    for task in &tasks {
+        store.delete(task.id)?;
+        println!("🗑️  Task {} removed: '{}'", task.id, task.title);
+    }
+    if tasks.len() > 1 {
+        println!("{} tasks removed.", tasks.len());
+    }
+
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,173 @@
+use crate::cli::args::ReportOptions;
+use crate::cli::chart::{burndown_lines, burndown_svg, flow_legend, flow_lines, flow_svg, FlowCounts};
+use crate::cli::format::{format_date, format_day};
+use crate::project::ProjectStore;
+use crate::repl::command_handler::ReplContext;
+use crate::task::dates::{end_of_day_timestamp, parse_date};
+use crate::task::{Status, TaskStore};
+use chrono::{Duration, Local, NaiveDate};
+
+struct ReportData {
+    title: String,
+    days: Vec<NaiveDate>,
+    // None for days that have not ended yet
+    counts: Vec<Option<FlowCounts>>,
+}
+
+pub fn report_burndown(options: ReportOptions, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let data = match load_report(&options, context)? {
+        Some(data) => data,
+        None => return Ok(()),
+    };
+
+    let remaining: Vec<Option<u32>> = data.counts.iter().map(|c| c.map(|c| c.remaining())).collect();
+    let start = remaining.first().copied().flatten().unwrap_or(0) as f64;
+    let last = data.days.len().saturating_sub(1).max(1) as f64;
+    let ideal: Vec<f64> = (0..data.days.len()).map(|i| start * (1.0 - i as f64 / last)).collect();
+    let labels = day_labels(&data.days);
+
+    println!("📉 Burndown{}", data.title);
+    for line in burndown_lines(&labels, &remaining, &ideal, options.ascii) {
+        println!("{}", line);
+    }
+
+    if let Some(path) = &options.svg {
+        std::fs::write(path, burndown_svg(&format!("Burndown{}", data.title), &labels, &remaining, &ideal))?;
+        println!("💾 Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+pub fn report_flow(options: ReportOptions, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let data = match load_report(&options, context)? {
+        Some(data) => data,
+        None => return Ok(()),
+    };
+
+    // The flow only covers days that have already happened
+    let (days, counts): (Vec<NaiveDate>, Vec<FlowCounts>) =
+        data.days.iter().zip(&data.counts).filter_map(|(day, c)| c.map(|c| (*day, c))).unzip();
+    let labels = day_labels(&days);
+
+    println!("📊 Cumulative flow{}", data.title);
+    println!("  {}", flow_legend(options.ascii));
+    for line in flow_lines(&labels, &counts, options.ascii) {
+        println!("{}", line);
+    }
+
+    if let Some(path) = &options.svg {
+        std::fs::write(path, flow_svg(&format!("Cumulative flow{}", data.title), &labels, &counts))?;
+        println!("💾 Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn load_report(options: &ReportOptions, context: &ReplContext) -> Result<Option<ReportData>, Box<dyn std::error::Error>> {
+    // An explicit --project wins over the REPL's current project
+    let project = match &options.project {
+        Some(name) => match ProjectStore::new()?.find_by_name(name)? {
+            Some(project) => Some((project.id, project.name)),
+            None => {
+                println!("❌ Project '{}' not found.", name);
+                return Ok(None);
+            }
+        },
+        None => context.current_project.as_ref().map(|p| (p.id, p.name.clone())),
+    };
+
+    let today = Local::now().date_naive();
+    let to = match &options.to {
+        Some(to) => parse_date(to)?,
+        None => today,
+    };
+    let from = match &options.from {
+        Some(from) => parse_date(from)?,
+        None => to - Duration::days(13),
+    };
+    if from > to {
+        return Err("--from must not be after --to".into());
+    }
+
+    let store = TaskStore::new()?;
+    let history = store.status_history(project.as_ref().map(|(id, _)| *id))?;
+
+    let mut days = Vec::new();
+    let mut counts = Vec::new();
+    let mut day = from;
+    while day <= to {
+        days.push(day);
+        counts.push(if day <= today {
+            Some(counts_at(&history, end_of_day_timestamp(day)?))
+        } else {
+            None
+        });
+        day += Duration::days(1);
+    }
+
+    let title = format!(
+        "{}: {} → {}",
+        project.map(|(_, name)| format!(" for '{}'", name)).unwrap_or_default(),
+        format_date(from),
+        format_date(to)
+    );
+
+    Ok(Some(ReportData { title, days, counts }))
+}
+
+// Count every task by the last status it had at the given moment. The history is
+// ordered by task, then time, so the last change seen for a task wins.
+fn counts_at(history: &[(u32, Status, i64)], moment: i64) -> FlowCounts {
+    let mut counts = FlowCounts::default();
+    let mut current: Option<(u32, &Status)> = None;
+
+    let mut add = |status: &Status| match status {
+        Status::Todo => counts.todo += 1,
+        Status::InProgress => counts.in_progress += 1,
+        Status::Done => counts.done += 1,
+    };
+
+    for (task_id, status, changed_at) in history {
+        if *changed_at > moment {
+            continue;
+        }
+        match current {
+            Some((id, _)) if id == *task_id => current = Some((id, status)),
+            Some((_, previous)) => {
+                add(previous);
+                current = Some((*task_id, status));
+            }
+            None => current = Some((*task_id, status)),
+        }
+    }
+    if let Some((_, status)) = current {
+        add(status);
+    }
+
+    counts
+}
+
+fn day_labels(days: &[NaiveDate]) -> Vec<String> {
+    days.iter().map(|d| format_day(*d)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_at_uses_latest_status_per_task() {
+        let history = vec![
+            (1, Status::Todo, 10),
+            (1, Status::InProgress, 20),
+            (1, Status::Done, 30),
+            (2, Status::Todo, 15),
+            (3, Status::Todo, 40),
+        ];
+
+        assert_eq!(counts_at(&history, 5), FlowCounts::default());
+        assert_eq!(counts_at(&history, 25), FlowCounts { todo: 1, in_progress: 1, done: 0 });
+        assert_eq!(counts_at(&history, 40), FlowCounts { todo: 2, in_progress: 0, done: 1 });
+    }
+}
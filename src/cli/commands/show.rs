@@ -0,0 +1,160 @@
+use crate::cli::commands::next::load_urgency_scorer;
+use crate::cli::format::{format_date, format_datetime, relative_due, relative_past, relative_start, status_emoji};
+use crate::project::ProjectStore;
+use crate::repl::command_handler::ReplContext;
+use crate::task::{Status, Task, TaskStore};
+use chrono::{DateTime, Local};
+use serde_json::Value;
+
+pub fn show_task(id: u32, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+
+    // Check if task exists
+    let task = match store.find_by_id(id)? {
+        Some(task) => task,
+        None => return context.report(format!("Task with ID {} not found.", id)),
+    };
+
+    let project_name = match task.project_id {
+        Some(project_id) => {
+            let project_store = ProjectStore::new()?;
+            Some(match project_store.find_by_id(project_id)? {
+                Some(project) => project.name,
+                None => format!("#{}", project_id),
+            })
+        }
+        None => None,
+    };
+
+    let now = Local::now();
+
+    print_breadcrumb(&store, &task, project_name.as_deref())?;
+    println!("{} [{}] {}", status_emoji(&task.status), task.id, task.title);
+    println!();
+    println!("  Status:    {}", task.status);
+    println!("  Priority:  {}", task.priority);
+
+    if !matches!(task.status, Status::Done) {
+        let scorer = load_urgency_scorer(&store)?;
+        println!("  Urgency:   {:.1}", scorer.score(&task));
+    }
+
+    if let Some(project_name) = &project_name {
+        println!("  Project:   {}", project_name);
+    }
+
+    if !task.tags.is_empty() {
+        println!("  Tags:      {}", task.tags.join(", "));
+    }
+
+    if let Some(due_date) = task.due_date_datetime() {
+        let relative = match task.status {
+            Status::Done => String::new(),
+            _ => format!(" ({})", relative_due(due_date, now)),
+        };
+        println!("  Due:       {}{}", format_date(due_date.date_naive()), relative);
+    }
+
+    if let Some(scheduled) = task.scheduled_date_datetime() {
+        let relative = if task.is_waiting(now.timestamp()) {
+            format!(" (waiting, {})", relative_start(scheduled, now))
+        } else {
+            String::new()
+        };
+        println!("  Scheduled: {}{}", format_date(scheduled.date_naive()), relative);
+    }
+
+    println!("  Created:   {}", format_timestamp(task.created_at_datetime(), now));
+    println!("  Updated:   {}", format_timestamp(task.updated_at_datetime(), now));
+
+    if let Some(completed_at) = task.completed_at_datetime() {
+        println!("  Completed: {}", format_timestamp(completed_at, now));
+    }
+
+    print_children(&store, &task)?;
+
+    if let Some(extras) = &task.extras {
+        print_extras(extras);
+    }
+
+    if let Some(notes) = &task.notes {
+        println!();
+        println!("Notes:");
+        for line in notes.lines() {
+            println!("  {}", line);
+        }
+    }
+
+    Ok(())
+}
+
+fn format_timestamp(time: DateTime<Local>, now: DateTime<Local>) -> String {
+    format!("{} ({})", format_datetime(time), relative_past(time, now))
+}
+
+fn print_breadcrumb(
+    store: &TaskStore,
+    task: &Task,
+    project_name: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Walk up the parent chain, guarding against cycles
+    let mut ancestors = Vec::new();
+    let mut next_parent = task.parent_id;
+    while let Some(parent_id) = next_parent {
+        if parent_id == task.id || ancestors.iter().any(|(id, _)| *id == parent_id) {
+            break;
+        }
+        match store.find_by_id(parent_id)? {
+            Some(parent) => {
+                next_parent = parent.parent_id;
+                ancestors.push((parent.id, parent.title));
+            }
+            None => break,
+        }
+    }
+
+    let mut crumbs: Vec<String> = project_name.map(|name| format!("@{}", name)).into_iter().collect();
+    crumbs.extend(ancestors.iter().rev().map(|(id, title)| format!("[{}] {}", id, title)));
+
+    if !crumbs.is_empty() {
+        println!("{} ›", crumbs.join(" › "));
+    }
+
+    Ok(())
+}
+
+fn print_children(store: &TaskStore, task: &Task) -> Result<(), Box<dyn std::error::Error>> {
+    let children = store.find_children(task.id)?;
+    if children.is_empty() {
+        return Ok(());
+    }
+
+    let done = children.iter().filter(|c| matches!(c.status, Status::Done)).count();
+
+    println!();
+    println!("Subtasks ({}/{} done):", done, children.len());
+    for child in &children {
+        println!("  {} [{}] {}", status_emoji(&child.status), child.id, child.title);
+    }
+
+    Ok(())
+}
+
+fn print_extras(extras: &Value) {
+    println!();
+    println!("Extras:");
+
+    match extras {
+        Value::Object(map) => {
+            let width = map.keys().map(|k| k.chars().count()).max().unwrap_or(0);
+            for (key, value) in map {
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                println!("  {:<width$}  {}", key, value, width = width);
+            }
+        }
+        other => println!("  {}", other),
+    }
+}
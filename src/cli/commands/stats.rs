@@ -0,0 +1,239 @@
+use crate::cli::args::StatsPeriod;
+use crate::cli::format::format_day;
+use crate::config::Config;
+use crate::project::ProjectStore;
+use crate::repl::command_handler::ReplContext;
+use crate::task::dates::start_of_day_timestamp;
+use crate::task::duration::format_minutes;
+use crate::task::TaskStore;
+use chrono::{Duration, Local, NaiveDate};
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Serialize)]
+struct StatsReport {
+    project: Option<String>,
+    period: String,
+    from: String,
+    to: String,
+    throughput: Vec<PeriodCounts>,
+    lead_time_seconds: Option<i64>,
+    completed_measured: u32,
+    due: DueStats,
+    by_status: Vec<NamedCount>,
+    by_priority: Vec<NamedCount>,
+    by_project: Vec<NamedCount>,
+    by_tag: Vec<NamedCount>,
+}
+
+#[derive(Serialize)]
+struct NamedCount {
+    name: String,
+    count: u32,
+}
+
+#[derive(Serialize)]
+struct PeriodCounts {
+    period: String,
+    created: u32,
+    completed: u32,
+}
+
+#[derive(Serialize)]
+struct DueStats {
+    measured: u32,
+    late: u32,
+    open_overdue: u32,
+    overdue_rate: Option<f64>,
+}
+
+pub fn stats(
+    by: StatsPeriod,
+    days: u32,
+    json: bool,
+    context: &ReplContext,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let now = Local::now();
+    let today = now.date_naive();
+    let weekly = matches!(by, StatsPeriod::Week);
+
+    // Weekly buckets start on the configured week start, so align the window to a whole week
+    let week_start = weekly.then(|| Config::current().display.week_start);
+    let mut from = today - Duration::days(days.max(1) as i64 - 1);
+    if let Some(week_start) = week_start {
+        from = week_start.start_of_week(from);
+    }
+    let since = start_of_day_timestamp(from)?;
+
+    let first_weekday = week_start.map(|w| w.weekday());
+    let created: HashMap<String, u32> = store.created_per_period(first_weekday, since, project_id)?.into_iter().collect();
+    let completed: HashMap<String, u32> = store.completed_per_period(first_weekday, since, project_id)?.into_iter().collect();
+
+    // Fill in empty periods so gaps show up as zeros
+    let step = if weekly { 7 } else { 1 };
+    let mut throughput = Vec::new();
+    let mut period = from;
+    while period <= today {
+        let key = period.format("%Y-%m-%d").to_string();
+        throughput.push(PeriodCounts {
+            created: created.get(&key).copied().unwrap_or(0),
+            completed: completed.get(&key).copied().unwrap_or(0),
+            period: key,
+        });
+        period += Duration::days(step);
+    }
+
+    let (lead_time, completed_measured) = store.lead_time(project_id)?;
+    let (measured, late, open_overdue) = store.due_counts(now.timestamp(), project_id)?;
+
+    let project_names: HashMap<u32, String> = ProjectStore::new()?.list()?.into_iter().map(|p| (p.id, p.name)).collect();
+    let by_project = match project_id {
+        Some(_) => Vec::new(),
+        None => store
+            .project_counts()?
+            .into_iter()
+            .map(|(id, count)| {
+                let name = match id {
+                    Some(id) => project_names.get(&id).cloned().unwrap_or_else(|| format!("#{}", id)),
+                    None => "(no project)".to_string(),
+                };
+                NamedCount { name, count }
+            })
+            .collect(),
+    };
+
+    let report = StatsReport {
+        project: context.current_project.as_ref().map(|p| p.name.clone()),
+        period: if weekly { "week" } else { "day" }.to_string(),
+        from: from.format("%Y-%m-%d").to_string(),
+        to: today.format("%Y-%m-%d").to_string(),
+        throughput,
+        lead_time_seconds: lead_time.map(|seconds| seconds.round() as i64),
+        completed_measured,
+        due: DueStats {
+            measured,
+            late,
+            open_overdue,
+            overdue_rate: (measured > 0).then(|| late as f64 / measured as f64),
+        },
+        by_status: named_counts(store.status_counts(project_id)?),
+        by_priority: named_counts(store.priority_counts(project_id)?),
+        by_project,
+        by_tag: named_counts(store.tag_counts(project_id)?),
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_report(&report, weekly);
+    }
+
+    Ok(())
+}
+
+fn named_counts<T: ToString>(counts: Vec<(T, u32)>) -> Vec<NamedCount> {
+    counts
+        .into_iter()
+        .map(|(name, count)| NamedCount { name: name.to_string(), count })
+        .collect()
+}
+
+fn print_report(report: &StatsReport, weekly: bool) {
+    let scope = match &report.project {
+        Some(name) => format!(" for project '{}'", name),
+        None => String::new(),
+    };
+    println!("📊 Stats{}: {} → {}", scope, report.from, report.to);
+
+    println!();
+    println!("Created vs completed per {}:", report.period);
+    let width = report
+        .throughput
+        .iter()
+        .map(|p| p.created.max(p.completed))
+        .max()
+        .unwrap_or(0)
+        .clamp(1, 30) as usize;
+    for period in &report.throughput {
+        let label = if weekly {
+            format!("wk {}", period.period)
+        } else {
+            NaiveDate::parse_from_str(&period.period, "%Y-%m-%d")
+                .map(format_day)
+                .unwrap_or_else(|_| period.period.clone())
+        };
+        let line = format!(
+            "  {:<14} +{:<3} ✓{:<3} {:<w$} {}",
+            label,
+            period.created,
+            period.completed,
+            "▒".repeat((period.created as usize).min(width)),
+            "█".repeat((period.completed as usize).min(width)),
+            w = width
+        );
+        println!("{}", line.trim_end());
+    }
+
+    println!();
+    match report.lead_time_seconds {
+        Some(seconds) => println!(
+            "Average lead time: {} over {} completed task(s)",
+            format_lead_time(seconds),
+            report.completed_measured
+        ),
+        None => println!("Average lead time: no completed tasks yet"),
+    }
+    match report.due.overdue_rate {
+        Some(rate) => println!(
+            "Overdue rate: {:.0}% ({} of {} tasks past their due date were late, {} still open)",
+            rate * 100.0,
+            report.due.late,
+            report.due.measured,
+            report.due.open_overdue
+        ),
+        None => println!("Overdue rate: no tasks with a passed due date"),
+    }
+
+    print_counts("By status", &report.by_status);
+    print_counts("By priority", &report.by_priority);
+    print_counts("By project", &report.by_project);
+    print_counts("By tag", &report.by_tag);
+}
+
+fn print_counts(title: &str, counts: &[NamedCount]) {
+    if counts.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("{}:", title);
+    let width = counts.iter().map(|c| c.name.chars().count()).max().unwrap_or(0);
+    for c in counts {
+        println!("  {:<width$}  {}", c.name, c.count, width = width);
+    }
+}
+
+fn format_lead_time(seconds: i64) -> String {
+    let minutes = seconds / 60;
+    match minutes / (24 * 60) {
+        0 => format_minutes(minutes),
+        days => match (minutes % (24 * 60)) / 60 {
+            0 => format!("{}d", days),
+            hours => format!("{}d {}h", days, hours),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_lead_time() {
+        assert_eq!(format_lead_time(45 * 60), "45m");
+        assert_eq!(format_lead_time(26 * 60 * 60), "1d 2h");
+        assert_eq!(format_lead_time(3 * 24 * 60 * 60 + 59), "3d");
+    }
+}
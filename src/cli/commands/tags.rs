@@ -0,0 +1,63 @@
+use crate::repl::command_handler::ReplContext;
+use crate::task::tags::{normalize_tag, normalize_tags};
+use crate::task::TaskStore;
+
+pub fn list_tags(context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let counts = store.tag_counts(context.current_project.as_ref().map(|p| p.id))?;
+
+    if counts.is_empty() {
+        println!("No tags found.");
+        return Ok(());
+    }
+
+    match &context.current_project {
+        Some(project) => println!("🏷️  Tags in project '{}':", project.name),
+        None => println!("🏷️  Tags:"),
+    }
+
+    let width = counts.iter().map(|(tag, _)| tag.chars().count()).max().unwrap_or(0);
+    for (tag, count) in counts {
+        println!("  {:<width$}  {}", tag, count, width = width);
+    }
+
+    Ok(())
+}
+
+pub fn rename_tag(old: &str, new: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let old = normalize_tag(old);
+    let new = normalize_tag(new);
+    if old.is_empty() || new.is_empty() {
+        return Err("Tag names cannot be empty".into());
+    }
+
+    let store = TaskStore::new()?;
+    let touched = store.merge_tags(std::slice::from_ref(&old), &new)?;
+
+    if touched == 0 {
+        println!("❌ No tasks tagged '{}'.", old);
+    } else {
+        println!("✅ Renamed tag '{}' to '{}' on {} task(s)", old, new, touched);
+    }
+
+    Ok(())
+}
+
+pub fn merge_tags(sources: &[String], into: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let sources = normalize_tags(sources);
+    let target = normalize_tag(into);
+    if sources.is_empty() || target.is_empty() {
+        return Err("Tag names cannot be empty".into());
+    }
+
+    let store = TaskStore::new()?;
+    let touched = store.merge_tags(&sources, &target)?;
+
+    if touched == 0 {
+        println!("❌ No tasks tagged {}.", sources.join(", "));
+    } else {
+        println!("✅ Merged {} into '{}' on {} task(s)", sources.join(", "), target, touched);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::cli::args::TimesheetGrouping;
+use crate::cli::format::format_date;
+use crate::config::Config;
+use crate::project::ProjectStore;
+use crate::repl::command_handler::ReplContext;
+use crate::task::dates::{end_of_day_timestamp, parse_date, start_of_day_timestamp};
+use crate::task::duration::format_minutes;
+use crate::task::{Task, TaskStore};
+use crate::timelog::TimeStore;
+use chrono::{Duration, Local};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+
+pub fn timesheet(
+    by: TimesheetGrouping,
+    from: Option<String>,
+    to: Option<String>,
+    context: &ReplContext,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let today = Local::now().date_naive();
+    let to = match to {
+        Some(to) => parse_date(&to)?,
+        None => today,
+    };
+    let from = match from {
+        Some(from) => parse_date(&from)?,
+        None => to - Duration::days(6),
+    };
+    if from > to {
+        return Err("--from must not be after --to".into());
+    }
+
+    let store = TaskStore::new()?;
+    let time_store = TimeStore::new()?;
+    let project_store = ProjectStore::new()?;
+    let project_map: HashMap<u32, String> = project_store.list()?.into_iter().map(|p| (p.id, p.name)).collect();
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let now = Local::now().timestamp();
+    let week_start = Config::current().display.week_start;
+
+    let entries = time_store.entries_between(start_of_day_timestamp(from)?, end_of_day_timestamp(to)?)?;
+
+    // group label -> task id -> seconds, with labels ordered by their sort key
+    let mut tasks: HashMap<u32, Option<Task>> = HashMap::new();
+    let mut groups: BTreeMap<(String, String), BTreeMap<u32, i64>> = BTreeMap::new();
+    for entry in &entries {
+        if let Entry::Vacant(slot) = tasks.entry(entry.task_id) {
+            slot.insert(store.find_by_id(entry.task_id)?);
+        }
+        let task = tasks[&entry.task_id].as_ref();
+        if project_id.is_some() && task.and_then(|t| t.project_id) != project_id {
+            continue;
+        }
+
+        let day = entry.started_at_datetime().date_naive();
+        let key = match by {
+            TimesheetGrouping::Day => (day.to_string(), format!("{} {}", day.format("%a"), format_date(day))),
+            TimesheetGrouping::Week => {
+                let week_start = week_start.start_of_week(day);
+                (week_start.to_string(), format!("Week of {}", format_date(week_start)))
+            }
+            TimesheetGrouping::Project => {
+                let name = task
+                    .and_then(|t| t.project_id)
+                    .and_then(|id| project_map.get(&id).cloned())
+                    .unwrap_or_else(|| "(no project)".to_string());
+                (name.clone(), name)
+            }
+        };
+
+        *groups.entry(key).or_default().entry(entry.task_id).or_default() += entry.duration_seconds(now);
+    }
+
+    let scope = context
+        .current_project
+        .as_ref()
+        .map(|p| format!(" in project '{}'", p.name))
+        .unwrap_or_default();
+    println!("⏱️  Timesheet{}: {} → {}", scope, format_date(from), format_date(to));
+
+    if groups.is_empty() {
+        println!("No time tracked.");
+        return Ok(());
+    }
+
+    let mut total = 0;
+    for ((_, label), task_seconds) in &groups {
+        let group_total: i64 = task_seconds.values().sum();
+        total += group_total;
+
+        println!();
+        println!("  {:<40} {:>8}", label, format_minutes(group_total / 60));
+        for (task_id, seconds) in task_seconds {
+            let title = tasks[task_id].as_ref().map(|t| t.title.as_str()).unwrap_or("(deleted task)");
+            println!("    {:<38} {:>8}", format!("[{}] {}", task_id, title), format_minutes(seconds / 60));
+        }
+    }
+
+    println!();
+    println!("  {:<40} {:>8}", "Total", format_minutes(total / 60));
+
+    Ok(())
+}
+
@@ -0,0 +1,116 @@
+use crate::cli::format::format_datetime;
+use crate::repl::command_handler::ReplContext;
+use crate::task::duration::{format_minutes, parse_duration_minutes};
+use crate::task::{Status, TaskStore};
+use crate::timelog::{TimeEntry, TimeStore};
+use chrono::Local;
+
+pub fn track_start(id: u32, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+    let time_store = TimeStore::new()?;
+    let now = Local::now().timestamp();
+
+    // Check if task exists
+    let task = match store.find_by_id(id)? {
+        Some(task) => task,
+        None => return context.report(format!("Task with ID {} not found.", id)),
+    };
+
+    // Only one timer runs at a time; switching tasks stops the current one
+    if let Some(active) = time_store.active()? {
+        if active.task_id == id {
+            println!("⏱️  Already tracking task {}: '{}'", id, task.title);
+            return Ok(());
+        }
+        if let Some(stopped) = time_store.stop(now)? {
+            print_stopped(&store, &time_store, &stopped, now)?;
+        }
+    }
+
+    time_store.start(id, now)?;
+
+    if matches!(task.status, Status::Todo) {
+        store.update_status(id, Status::InProgress)?;
+    }
+
+    println!("▶️  Tracking task {}: '{}'", id, task.title);
+
+    Ok(())
+}
+
+pub fn track_stop() -> Result<(), Box<dyn std::error::Error>> {
+    let time_store = TimeStore::new()?;
+    let now = Local::now().timestamp();
+
+    let entry = match time_store.stop(now)? {
+        Some(entry) => entry,
+        None => {
+            println!("No timer is running.");
+            return Ok(());
+        }
+    };
+
+    let store = TaskStore::new()?;
+    print_stopped(&store, &time_store, &entry, now)
+}
+
+fn print_stopped(
+    store: &TaskStore,
+    time_store: &TimeStore,
+    entry: &TimeEntry,
+    now: i64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let title = store.find_by_id(entry.task_id)?.map(|t| t.title).unwrap_or_default();
+    let total = time_store.totals_by_task(now)?.get(&entry.task_id).copied().unwrap_or(0);
+
+    println!(
+        "⏹️  Stopped task {}: '{}' after {} (total {})",
+        entry.task_id,
+        title,
+        format_minutes(entry.duration_seconds(now) / 60),
+        format_minutes(total / 60)
+    );
+
+    Ok(())
+}
+
+pub fn track_log(id: u32, duration: &str, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let minutes = parse_duration_minutes(duration)?;
+    let store = TaskStore::new()?;
+
+    // Check if task exists
+    let task = match store.find_by_id(id)? {
+        Some(task) => task,
+        None => return context.report(format!("Task with ID {} not found.", id)),
+    };
+
+    let time_store = TimeStore::new()?;
+    let now = Local::now().timestamp();
+    time_store.log(id, minutes as i64 * 60, now, "manual")?;
+
+    println!("✅ Logged {} on task {}: '{}'", format_minutes(minutes as i64), id, task.title);
+
+    Ok(())
+}
+
+pub fn track_status() -> Result<(), Box<dyn std::error::Error>> {
+    let time_store = TimeStore::new()?;
+    let now = Local::now().timestamp();
+
+    match time_store.active()? {
+        Some(entry) => {
+            let store = TaskStore::new()?;
+            let title = store.find_by_id(entry.task_id)?.map(|t| t.title).unwrap_or_default();
+            println!(
+                "⏱️  Tracking task {}: '{}' for {} (since {})",
+                entry.task_id,
+                title,
+                format_minutes(entry.duration_seconds(now) / 60),
+                format_datetime(entry.started_at_datetime())
+            );
+        }
+        None => println!("No timer is running."),
+    }
+
+    Ok(())
+}
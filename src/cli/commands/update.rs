@@ -1,29 +1,39 @@
 use crate::cli::metadata::parse_slash_metadata;
-use crate::task::{TaskDraft, TaskStore};
+use crate::task::duration::parse_duration_minutes;
+use crate::task::tags::apply_tag_changes;
+use crate::task::model::Priority;
+use crate::task::{Status, Task, TaskStore};
 use chrono::Local;
-use crate::editor::edit_toml_content;
+use crate::cli::commands::add::parse_task;
+use crate::editor::edit_until_valid;
+use crate::project::ProjectStore;
+use crate::cli::target::{confirm_bulk, parse_target, resolve_target, TaskTarget};
+use crate::repl::command_handler::ReplContext;
+use crate::task::template::TaskFields;
 
-pub fn update_task(id: u32, text: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
-    // Check if we have slash-style metadata or should use TOML editor
-    if let Some(text_input) = text {
-        return update_task_with_metadata(id, &text_input);
-    } else {
-        return update_task_with_editor(id);
+pub fn update_task(args: &[String], dry_run: bool, yes: bool, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let (target, changes) = parse_target(args)?;
+    let text = changes.join(" ");
+
+    // Without slash-style metadata a single task opens in the TOML editor
+    if text.trim().is_empty() {
+        return match target {
+            TaskTarget::Ids(ids) if ids.len() == 1 && !dry_run => update_task_with_editor(ids[0], context),
+            _ => Err("Give the changes to apply, e.g. 'update 3,5 /p high', or use 'edit --bulk' to edit several tasks".into()),
+        };
     }
+    update_tasks_with_metadata(&target, &text, dry_run, yes, context)
 }
 
-fn update_task_with_metadata(id: u32, text: &str) -> Result<(), Box<dyn std::error::Error>> {
+fn update_tasks_with_metadata(
+    target: &TaskTarget,
+    text: &str,
+    dry_run: bool,
+    yes: bool,
+    context: &ReplContext,
+) -> Result<(), Box<dyn std::error::Error>> {
     let store = TaskStore::new()?;
     
-    // Find the task
-    let mut task = match store.find_by_id(id)? {
-        Some(task) => task,
-        None => {
-            println!("❌ Task with ID {} not found.", id);
-            return Ok(());
-        }
-    };
-    
     // Parse slash metadata for update command
     let metadata = if text.starts_with('/') {
         // Metadata-only update: /p high /tag work
@@ -35,73 +45,127 @@ fn update_task_with_metadata(id: u32, text: &str) -> Result<(), Box<dyn std::err
         parse_slash_metadata(text)?
     };
     
-    // Update task fields based on metadata
-    // For update, only update title if it's not the dummy and if the input doesn't start with /
-    if !text.starts_with('/') && !metadata.title.is_empty() {
-        task.title = metadata.title;
-    }
-    
-    if let Some(priority) = metadata.priority {
-        let mut draft = task.to_draft();
-        draft.priority = priority;
-        task = draft.to_task()?;
-        task.id = id; // Preserve ID
+    let tasks = resolve_target(&store, target, context)?;
+    if tasks.is_empty() {
+        return Ok(());
     }
-    
-    if let Some(due_date) = metadata.due_date {
-        task.due_date = Some(due_date);
-    }
-    
-    if !metadata.tags.is_empty() {
-        task.tags = metadata.tags;
+
+    // For update, only update title if it's not the dummy and if the input doesn't start with /
+    let title = (!text.starts_with('/') && !metadata.title.is_empty()).then_some(metadata.title.as_str());
+    if title.is_some() && tasks.len() > 1 {
+        return Err("A new title can only be given when updating a single task".into());
     }
-    
+    let estimate_minutes = metadata.estimate.as_deref().map(parse_duration_minutes).transpose()?;
     if let Some(parent_id) = metadata.parent {
         // Validate parent exists
-        match store.find_by_id(parent_id)? {
-            Some(_) => task.parent_id = Some(parent_id),
-            None => return Err(format!("Parent task with ID {} not found", parent_id).into()),
+        if store.find_by_id(parent_id)?.is_none() {
+            return Err(format!("Parent task with ID {} not found", parent_id).into());
+        }
+        // A task that is its own parent drops out of the task tree
+        if tasks.iter().any(|t| t.id == parent_id) {
+            return Err(format!("Task {} cannot be its own parent; leave it out of the targeted tasks", parent_id).into());
         }
     }
-    
-    // Update timestamps
-    task.updated_at = Local::now().timestamp();
-    
-    // Update task in database
-    store.update(&task)?;
-    
-    println!("✅ Task {} updated: '{}'", id, task.title);
+
+    if !dry_run && !confirm_bulk("update", tasks.iter().map(|t| (t.id, t.title.as_str())), yes, context)? {
+        return Ok(());
+    }
+
+    let projects = ProjectStore::new()?.list()?;
+    let count = tasks.len();
+    for mut task in tasks {
+        let before = TaskFields::from_draft(&task.to_draft(), &projects);
+
+        // Update task fields based on metadata
+        if let Some(title) = title {
+            task.title = title.to_string();
+        }
+        if let Some(priority) = &metadata.priority {
+            task.priority = Priority::from_string(priority);
+        }
+        if let Some(due_date) = metadata.due_date {
+            task.due_date = Some(due_date);
+        }
+        if let Some(scheduled_date) = metadata.scheduled_date {
+            task.scheduled_date = Some(scheduled_date);
+        }
+        if let Some(estimate_minutes) = estimate_minutes {
+            task.estimate_minutes = Some(estimate_minutes);
+        }
+        if !metadata.tags.is_empty() {
+            task.tags = metadata.tags.clone();
+        }
+        apply_tag_changes(&mut task.tags, &metadata.tags_added, &metadata.tags_removed);
+        if let Some(parent_id) = metadata.parent {
+            task.parent_id = Some(parent_id);
+        }
+
+        if dry_run {
+            let changes = describe_changes(&before, &TaskFields::from_draft(&task.to_draft(), &projects));
+            match changes.is_empty() {
+                true => println!("Task {} would stay the same: '{}'", task.id, task.title),
+                false => println!("Would update task {} '{}': {}", task.id, task.title, changes.join(", ")),
+            }
+            continue;
+        }
+
+        // Update timestamps
+        task.updated_at = Local::now().timestamp();
+        
+        // Update task in database
+        store.update(&task)?;
+        
+        println!("✅ Task {} updated: '{}'", task.id, task.title);
+    }
+    if count > 1 && !dry_run {
+        println!("{} tasks updated.", count);
+    }
     
     Ok(())
 }
 
-fn update_task_with_editor(id: u32) -> Result<(), Box<dyn std::error::Error>> {
+// 'priority: medium -> high' for each field that differs
+fn describe_changes(before: &TaskFields, after: &TaskFields) -> Vec<String> {
+    let show = |value: &str| if value.is_empty() { "(none)".to_string() } else { value.to_string() };
+    let parent = |parent: Option<u32>| parent.map(|id| id.to_string()).unwrap_or_default();
+
+    [
+        ("title", before.title.clone(), after.title.clone()),
+        ("priority", before.priority.clone(), after.priority.clone()),
+        ("due", before.due.clone(), after.due.clone()),
+        ("scheduled", before.scheduled.clone(), after.scheduled.clone()),
+        ("estimate", before.estimate.clone(), after.estimate.clone()),
+        ("tags", before.tags.join(", "), after.tags.join(", ")),
+        ("parent", parent(before.parent), parent(after.parent)),
+    ]
+    .into_iter()
+    .filter(|(_, old, new)| old != new)
+    .map(|(field, old, new)| format!("{}: {} -> {}", field, show(&old), show(&new)))
+    .collect()
+}
+
+fn update_task_with_editor(id: u32, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
     let store = TaskStore::new()?;
     
     // Find the task
     let task = match store.find_by_id(id)? {
         Some(task) => task,
-        None => {
-            println!("❌ Task with ID {} not found.", id);
-            return Ok(());
-        }
+        None => return context.report(format!("Task with ID {} not found.", id)),
     };
     
     // Convert task to draft and edit using shared editor utility
     let draft = task.to_draft();
-    let toml_content = draft.to_toml()?;
-    let edited_content = edit_toml_content(&toml_content)?;
-    
-    // Parse TOML and convert to task
-    let task_draft = TaskDraft::from_toml(&edited_content)
-        .map_err(|e| format!("Invalid TOML: {}", e))?;
-    
-    let mut updated_task = task_draft.to_task()
-        .map_err(|e| e)?;
+    let projects = ProjectStore::new()?.list()?;
+    let toml_content = draft.to_toml(&projects)?;
+    let mut updated_task = match edit_until_valid(&toml_content, |content| parse_task(content, &projects))? {
+        Some(task) => task,
+        None => {
+            println!("❌ Cancelled: the file was left empty. Task {} is unchanged.", id);
+            return Ok(());
+        }
+    };
     
-    // Keep the original ID and created_at
-    updated_task.id = task.id;
-    updated_task.created_at = task.created_at;
+    keep_identity(&mut updated_task, &task);
     
     // Update task in database
     store.update(&updated_task)?;
@@ -109,4 +173,31 @@ fn update_task_with_editor(id: u32) -> Result<(), Box<dyn std::error::Error>> {
     println!("✅ Task {} updated: '{}'", id, updated_task.title);
     
     Ok(())
-}
\ No newline at end of file
+}
+
+// Keep the original ID and created_at, and the completion time if it stays done
+pub fn keep_identity(updated: &mut Task, original: &Task) {
+    updated.id = original.id;
+    updated.created_at = original.created_at;
+    if matches!(updated.status, Status::Done) && original.completed_at.is_some() {
+        updated.completed_at = original.completed_at;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_changes() {
+        let before = TaskFields { priority: "medium".to_string(), tags: vec!["job".to_string()], ..TaskFields::default() };
+        let after = TaskFields {
+            priority: "high".to_string(),
+            due: "2025-08-10".to_string(),
+            tags: vec!["job".to_string()],
+            ..TaskFields::default()
+        };
+        assert_eq!(describe_changes(&before, &after), vec!["priority: medium -> high", "due: (none) -> 2025-08-10"]);
+        assert!(describe_changes(&before, &before).is_empty());
+    }
+}
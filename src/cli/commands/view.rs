@@ -0,0 +1,99 @@
+use crate::cli::commands::list_tasks;
+use crate::cli::filter::parse_filter;
+use crate::project::ProjectStore;
+use crate::repl::command_handler::ReplContext;
+use crate::view::model::pick_view;
+use crate::view::{SavedView, ViewStore};
+use std::collections::HashMap;
+
+pub fn save_view(name: &str, query: &[String], global: bool, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    // Validate the query before storing it
+    let query = query.join(" ");
+    parse_filter(&query)?;
+
+    let project = if global { None } else { context.current_project.as_ref() };
+    let view = SavedView::new(name, &query, project.map(|p| p.id))?;
+
+    let store = ViewStore::new()?;
+    store.save(&view)?;
+
+    match project {
+        Some(project) => println!("✅ View '{}' saved for project '{}': {}", view.name, project.name, view.query),
+        None => println!("✅ View '{}' saved: {}", view.name, view.query),
+    }
+
+    Ok(())
+}
+
+pub fn run_view(name: &str, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = ViewStore::new()?;
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let view = match pick_view(store.find_by_name(name)?, project_id)? {
+        Some(view) => view,
+        None => {
+            println!("❌ View '{}' not found. Use 'view list' to see saved views.", name);
+            return Ok(());
+        }
+    };
+
+    println!("🔎 {}: {}", view.name, view.query);
+    let query = vec![view.query.clone()];
+
+    // Project-scoped views always run against their project
+    match view.project_id {
+        Some(project_id) => {
+            let project_store = ProjectStore::new()?;
+            let mut scoped = ReplContext::new();
+            scoped.set_current_project(project_store.find_by_id(project_id)?);
+            list_tasks(&query, false, None, &scoped)
+        }
+        None => list_tasks(&query, false, None, context),
+    }
+}
+
+pub fn list_views(context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = ViewStore::new()?;
+    let project_store = ProjectStore::new()?;
+    let project_map: HashMap<u32, String> = project_store.list()?.into_iter().map(|p| (p.id, p.name)).collect();
+
+    // Inside a project only its own views and global views apply
+    let current_project_id = context.current_project.as_ref().map(|p| p.id);
+    let views: Vec<SavedView> = store
+        .list()?
+        .into_iter()
+        .filter(|v| current_project_id.is_none() || v.project_id.is_none() || v.project_id == current_project_id)
+        .collect();
+
+    if views.is_empty() {
+        println!("No saved views found.");
+        return Ok(());
+    }
+
+    println!("🔎 Saved views:");
+    let width = views.iter().map(|v| v.name.chars().count()).max().unwrap_or(0);
+    for view in views {
+        let scope = view
+            .project_id
+            .map(|id| format!(" @{}", project_map.get(&id).cloned().unwrap_or_else(|| format!("#{}", id))))
+            .unwrap_or_default();
+        println!("  {:<width$}  {}{}", view.name, view.query, scope, width = width);
+    }
+
+    Ok(())
+}
+
+pub fn delete_view(name: &str, context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let store = ViewStore::new()?;
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+    let deleted = match pick_view(store.find_by_name(name)?, project_id)? {
+        Some(view) => store.delete(&view.name, view.project_id)?,
+        None => 0,
+    };
+    if deleted == 0 {
+        println!("❌ View '{}' not found.", name);
+    } else {
+        println!("🗑️  View '{}' removed", name);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use crate::cli::metadata::split_slash_segments;
+use crate::task::dates::parse_date;
+use crate::task::tags::normalize_tag;
+use crate::task::Task;
+use chrono::NaiveDate;
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum SortKey {
+    Urgency,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateComparison {
+    Before,
+    OnOrBefore,
+    On,
+    OnOrAfter,
+    After,
+}
+
+impl DateComparison {
+    fn matches(&self, date: NaiveDate, target: NaiveDate) -> bool {
+        match self {
+            DateComparison::Before => date < target,
+            DateComparison::OnOrBefore => date <= target,
+            DateComparison::On => date == target,
+            DateComparison::OnOrAfter => date >= target,
+            DateComparison::After => date > target,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TaskFilter {
+    pub tags: Vec<String>,
+    pub statuses: Vec<String>,
+    pub priorities: Vec<String>,
+    pub due: Option<(DateComparison, NaiveDate)>,
+    pub group_by: Option<String>,
+    pub sort: Option<SortKey>,
+}
+
+impl TaskFilter {
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+            && self.statuses.is_empty()
+            && self.priorities.is_empty()
+            && self.due.is_none()
+            && self.group_by.is_none()
+            && self.sort.is_none()
+    }
+
+    // Tags are matched by the store query; this checks the remaining criteria
+    pub fn matches(&self, task: &Task) -> bool {
+        if !self.statuses.is_empty() && !self.statuses.contains(&task.status.to_string()) {
+            return false;
+        }
+
+        if !self.priorities.is_empty() && !self.priorities.contains(&task.priority.to_string()) {
+            return false;
+        }
+
+        if let Some((comparison, target)) = self.due {
+            match task.due_date_datetime() {
+                Some(due) if comparison.matches(due.date_naive(), target) => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
+pub fn parse_filter(input: &str) -> Result<TaskFilter, String> {
+    let parts = split_slash_segments(input);
+    let mut filter = TaskFilter::default();
+
+    if !parts[0].trim().is_empty() {
+        return Err(format!("Filters must start with '/': {}", parts[0].trim()));
+    }
+
+    for part in parts.iter().skip(1).map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        let (key, value) = match part.split_once(' ') {
+            Some((key, value)) => (key, value.trim()),
+            None => return Err(format!("Invalid filter format: /{}", part)),
+        };
+
+        match key {
+            "tag" | "tags" => {
+                for tag in split_values(value).map(normalize_tag) {
+                    if !tag.is_empty() && !filter.tags.contains(&tag) {
+                        filter.tags.push(tag);
+                    }
+                }
+            }
+            "status" => {
+                for status in split_values(value) {
+                    let status = status.to_lowercase();
+                    if !matches!(status.as_str(), "todo" | "in_progress" | "done") {
+                        return Err(format!("Invalid status: {}. Use: todo, in_progress, or done", status));
+                    }
+                    filter.statuses.push(status);
+                }
+            }
+            "p" | "priority" => {
+                for priority in split_values(value) {
+                    let priority = priority.to_lowercase();
+                    if !matches!(priority.as_str(), "low" | "medium" | "high" | "urgent") {
+                        return Err(format!("Invalid priority: {}. Use: low, medium, high, or urgent", priority));
+                    }
+                    filter.priorities.push(priority);
+                }
+            }
+            "due" => {
+                filter.due = Some(parse_date_comparison(value)?);
+            }
+            "group" => {
+                filter.group_by = Some(normalize_tag(value));
+            }
+            "sort" => {
+                filter.sort = Some(SortKey::from_str(value, true).map_err(|_| format!("Invalid sort: {}. Use: urgency", value))?);
+            }
+            _ => return Err(format!("Unknown filter key: {}", key)),
+        }
+    }
+
+    Ok(filter)
+}
+
+fn split_values(value: &str) -> impl Iterator<Item = &str> {
+    value.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty())
+}
+
+fn parse_date_comparison(value: &str) -> Result<(DateComparison, NaiveDate), String> {
+    let (comparison, date) = if let Some(date) = value.strip_prefix("<=") {
+        (DateComparison::OnOrBefore, date)
+    } else if let Some(date) = value.strip_prefix(">=") {
+        (DateComparison::OnOrAfter, date)
+    } else if let Some(date) = value.strip_prefix('<') {
+        (DateComparison::Before, date)
+    } else if let Some(date) = value.strip_prefix('>') {
+        (DateComparison::After, date)
+    } else {
+        (DateComparison::On, value.strip_prefix('=').unwrap_or(value))
+    };
+
+    Ok((comparison, parse_date(date)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Local};
+
+    #[test]
+    fn test_parse_tag_filter() {
+        let filter = parse_filter("/tag Work,urgent").unwrap();
+        assert_eq!(filter.tags, vec!["work", "urgent"]);
+    }
+
+    #[test]
+    fn test_parse_group_filter() {
+        let filter = parse_filter("/tag client /group Area").unwrap();
+        assert_eq!(filter.tags, vec!["client"]);
+        assert_eq!(filter.group_by, Some("area".to_string()));
+    }
+
+    #[test]
+    fn test_parse_status_priority_and_due() {
+        let filter = parse_filter("/status todo,in_progress /p HIGH /due <=today").unwrap();
+        assert_eq!(filter.statuses, vec!["todo", "in_progress"]);
+        assert_eq!(filter.priorities, vec!["high"]);
+        assert_eq!(filter.due, Some((DateComparison::OnOrBefore, Local::now().date_naive())));
+    }
+
+    #[test]
+    fn test_due_filter_matches_tasks() {
+        let mut draft = crate::task::TaskDraft::new();
+        draft.title = "Report".to_string();
+        let mut task = draft.into_task().unwrap();
+
+        let filter = parse_filter("/due <=tomorrow").unwrap();
+        assert!(!filter.matches(&task));
+
+        task.due_date = Some((Local::now() + Duration::days(1)).timestamp());
+        assert!(filter.matches(&task));
+
+        task.due_date = Some((Local::now() + Duration::days(3)).timestamp());
+        assert!(!filter.matches(&task));
+    }
+
+    #[test]
+    fn test_parse_sort_filter() {
+        let filter = parse_filter("/tag work /sort urgency").unwrap();
+        assert_eq!(filter.sort, Some(SortKey::Urgency));
+        assert!(parse_filter("/sort title").is_err());
+    }
+
+    #[test]
+    fn test_parse_filter_rejects_unknown_key() {
+        assert!(parse_filter("/color red").is_err());
+        assert!(parse_filter("work").is_err());
+        assert!(parse_filter("/status blocked").is_err());
+    }
+}
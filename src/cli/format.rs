@@ -0,0 +1,106 @@
+use crate::config::{Config, Glyphs};
+use crate::task::Status;
+use chrono::{DateTime, Local, NaiveDate};
+
+pub fn status_emoji(status: &Status) -> &'static str {
+    match (Config::current().display.glyphs, status) {
+        (Glyphs::Emoji, Status::Todo) => "⏳",
+        (Glyphs::Emoji, Status::InProgress) => "🔄",
+        (Glyphs::Emoji, Status::Done) => "✅",
+        (Glyphs::Ascii, Status::Todo) => "[ ]",
+        (Glyphs::Ascii, Status::InProgress) => "[~]",
+        (Glyphs::Ascii, Status::Done) => "[x]",
+    }
+}
+
+// Dates shown to the user follow display.date_format / display.datetime_format
+pub fn format_date(date: NaiveDate) -> String {
+    date.format(&Config::current().display.date_format).to_string()
+}
+
+// The weekday in front of the configured date, e.g. 'Sun 2026-10-18'
+pub fn format_day(date: NaiveDate) -> String {
+    format!("{} {}", date.format("%a"), format_date(date))
+}
+
+pub fn format_datetime(time: DateTime<Local>) -> String {
+    time.format(&Config::current().display.datetime_format).to_string()
+}
+
+pub fn relative_due(due: DateTime<Local>, now: DateTime<Local>) -> String {
+    let days = (due.date_naive() - now.date_naive()).num_days();
+
+    match days {
+        d if d < 0 => format!("overdue by {}d", -d),
+        0 if due < now => format!("overdue by {}h", (now - due).num_hours().max(1)),
+        0 => "due today".to_string(),
+        1 => "due tomorrow".to_string(),
+        d => format!("due in {} days", d),
+    }
+}
+
+pub fn relative_start(start: DateTime<Local>, now: DateTime<Local>) -> String {
+    match (start.date_naive() - now.date_naive()).num_days() {
+        d if d <= 0 => "started".to_string(),
+        1 => "starts tomorrow".to_string(),
+        d => format!("starts in {} days", d),
+    }
+}
+
+pub fn relative_past(time: DateTime<Local>, now: DateTime<Local>) -> String {
+    let elapsed = now - time;
+
+    if elapsed.num_minutes() < 1 {
+        "just now".to_string()
+    } else if elapsed.num_hours() < 1 {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed.num_days() < 1 {
+        format!("{}h ago", elapsed.num_hours())
+    } else if elapsed.num_days() == 1 {
+        "yesterday".to_string()
+    } else {
+        format!("{} days ago", elapsed.num_days())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_relative_due_future() {
+        let now = at(2025, 8, 10, 12);
+        assert_eq!(relative_due(at(2025, 8, 10, 23), now), "due today");
+        assert_eq!(relative_due(at(2025, 8, 11, 23), now), "due tomorrow");
+        assert_eq!(relative_due(at(2025, 8, 12, 23), now), "due in 2 days");
+    }
+
+    #[test]
+    fn test_relative_due_overdue() {
+        let now = at(2025, 8, 10, 12);
+        assert_eq!(relative_due(at(2025, 8, 9, 23), now), "overdue by 1d");
+        assert_eq!(relative_due(at(2025, 8, 10, 9), now), "overdue by 3h");
+    }
+
+    #[test]
+    fn test_relative_start() {
+        let now = at(2025, 8, 10, 12);
+        assert_eq!(relative_start(at(2025, 8, 10, 0), now), "started");
+        assert_eq!(relative_start(at(2025, 8, 11, 0), now), "starts tomorrow");
+        assert_eq!(relative_start(at(2025, 8, 15, 0), now), "starts in 5 days");
+    }
+
+    #[test]
+    fn test_relative_past() {
+        let now = at(2025, 8, 10, 12);
+        assert_eq!(relative_past(now, now), "just now");
+        assert_eq!(relative_past(at(2025, 8, 10, 9), now), "3h ago");
+        assert_eq!(relative_past(at(2025, 8, 9, 12), now), "yesterday");
+        assert_eq!(relative_past(at(2025, 8, 1, 12), now), "9 days ago");
+    }
+}
@@ -1,11 +1,16 @@
-use chrono::{Datelike, Local, NaiveDate, TimeZone};
+use crate::task::dates::{parse_date, parse_due_date, start_of_day_timestamp};
+use crate::task::duration::parse_duration_minutes;
+use crate::task::tags::normalize_tag;
 
 #[derive(Debug, Clone)]
 pub struct TaskMetadata {
     pub title: String,
     pub priority: Option<String>,
     pub due_date: Option<i64>,
+    pub scheduled_date: Option<i64>,
     pub tags: Vec<String>,
+    pub tags_added: Vec<String>,
+    pub tags_removed: Vec<String>,
     pub estimate: Option<String>,
     pub parent: Option<u32>,
 }
@@ -16,15 +21,37 @@ impl TaskMetadata {
             title,
             priority: None,
             due_date: None,
+            scheduled_date: None,
             tags: Vec::new(),
+            tags_added: Vec::new(),
+            tags_removed: Vec::new(),
             estimate: None,
             parent: None,
         }
     }
 }
 
+// Split input into the leading text and the '/key value' segments that follow it.
+// Only a '/' that starts a word opens a segment, so values like 'client/acme' stay intact.
+pub fn split_slash_segments(input: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut previous = ' ';
+
+    for (i, c) in input.char_indices() {
+        if c == '/' && previous.is_whitespace() {
+            segments.push(&input[start..i]);
+            start = i + 1;
+        }
+        previous = c;
+    }
+    segments.push(&input[start..]);
+
+    segments
+}
+
 pub fn parse_slash_metadata(input: &str) -> Result<TaskMetadata, String> {
-    let parts: Vec<&str> = input.split('/').collect();
+    let parts = split_slash_segments(input);
     
     if parts.is_empty() {
         return Err("No input provided".to_string());
@@ -66,13 +93,14 @@ pub fn parse_slash_metadata(input: &str) -> Result<TaskMetadata, String> {
             "due" => {
                 metadata.due_date = Some(parse_due_date(value)?);
             }
+            "sched" | "scheduled" | "wait" => {
+                metadata.scheduled_date = Some(start_of_day_timestamp(parse_date(value)?)?);
+            }
             "tag" | "tags" => {
-                metadata.tags = value.split(',')
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect();
+                parse_tag_value(value, &mut metadata);
             }
             "est" | "estimate" => {
+                parse_duration_minutes(value)?;
                 metadata.estimate = Some(value.to_string());
             }
             "parent" => {
@@ -90,41 +118,29 @@ pub fn parse_slash_metadata(input: &str) -> Result<TaskMetadata, String> {
     Ok(metadata)
 }
 
-fn parse_due_date(date_str: &str) -> Result<i64, String> {
-    // Try parsing various date formats
-    
-    // ISO format: 2025-08-10
-    if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-        let datetime = date.and_hms_opt(23, 59, 59)
-            .ok_or("Invalid date")?;
-        return Ok(Local.from_local_datetime(&datetime).single()
-            .ok_or("Invalid date conversion")?.timestamp());
-    }
-    
-    // US format: 08/10/2025
-    if let Ok(date) = NaiveDate::parse_from_str(date_str, "%m/%d/%Y") {
-        let datetime = date.and_hms_opt(23, 59, 59)
-            .ok_or("Invalid date")?;
-        return Ok(Local.from_local_datetime(&datetime).single()
-            .ok_or("Invalid date conversion")?.timestamp());
-    }
-    
-    // Short format: 08-10 (current year)
-    let current_year = Local::now().year();
-    let with_year = format!("{}-{}", current_year, date_str);
-    if let Ok(date) = NaiveDate::parse_from_str(&with_year, "%Y-%m-%d") {
-        let datetime = date.and_hms_opt(23, 59, 59)
-            .ok_or("Invalid date")?;
-        return Ok(Local.from_local_datetime(&datetime).single()
-            .ok_or("Invalid date conversion")?.timestamp());
+fn parse_tag_value(value: &str, metadata: &mut TaskMetadata) {
+    // Tags are comma or space separated; '+tag' and '-tag' add to or remove from existing tags
+    let tokens = value.split(|c: char| c == ',' || c.is_whitespace()).filter(|s| !s.is_empty());
+
+    for token in tokens {
+        let (target, tag) = match token.strip_prefix('+') {
+            Some(tag) => (&mut metadata.tags_added, tag),
+            None => match token.strip_prefix('-') {
+                Some(tag) => (&mut metadata.tags_removed, tag),
+                None => (&mut metadata.tags, token),
+            },
+        };
+        let tag = normalize_tag(tag);
+        if !tag.is_empty() && !target.contains(&tag) {
+            target.push(tag);
+        }
     }
-    
-    Err(format!("Invalid date format: {}. Use YYYY-MM-DD, MM/DD/YYYY, or MM-DD", date_str))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::{Duration, Local};
 
     #[test]
     fn test_parse_simple_title() {
@@ -166,6 +182,41 @@ mod tests {
         assert_eq!(result.tags, vec!["work", "urgent"]);
     }
 
+    #[test]
+    fn test_parse_tags_normalized() {
+        let result = parse_slash_metadata("Fix the bug /tag Work, #Urgent work").unwrap();
+        assert_eq!(result.tags, vec!["work", "urgent"]);
+    }
+
+    #[test]
+    fn test_parse_tag_additions_and_removals() {
+        let result = parse_slash_metadata("DUMMY_TITLE /tag +Urgent -later").unwrap();
+        assert!(result.tags.is_empty());
+        assert_eq!(result.tags_added, vec!["urgent"]);
+        assert_eq!(result.tags_removed, vec!["later"]);
+    }
+
+    #[test]
+    fn test_parse_hierarchical_tags() {
+        let result = parse_slash_metadata("Call Acme /tag client/acme,area:backend /p high").unwrap();
+        assert_eq!(result.tags, vec!["client/acme", "area:backend"]);
+        assert_eq!(result.priority, Some("high".to_string()));
+    }
+
+    #[test]
+    fn test_slash_inside_title_and_dates() {
+        let result = parse_slash_metadata("Fix I/O errors /due 08/10/2025").unwrap();
+        assert_eq!(result.title, "Fix I/O errors");
+        assert!(result.due_date.is_some());
+    }
+
+    #[test]
+    fn test_parse_scheduled_date() {
+        let result = parse_slash_metadata("Plan Q4 /wait tomorrow").unwrap();
+        let expected = start_of_day_timestamp(Local::now().date_naive() + Duration::days(1)).unwrap();
+        assert_eq!(result.scheduled_date, Some(expected));
+    }
+
     #[test]
     fn test_parse_multiple_metadata() {
         let result = parse_slash_metadata("Prepare slides /p 1 /due 2025-08-10 /tag work,presentation /est 2h").unwrap();
@@ -199,16 +250,17 @@ mod tests {
         }
         
         // Convert to Task
-        let task = draft.to_task().unwrap();
+        let task = draft.into_task().unwrap();
         
         // Check the priority was set correctly
         assert_eq!(task.title, "Test task");
         
         // The priority should be High, not Medium (the default)
-        match task.priority {
-            Priority::High => assert!(true),
-            other => panic!("Expected Priority::High, got {:?}", other),
-        }
+        assert!(
+            matches!(task.priority, Priority::High),
+            "Expected Priority::High, got {:?}",
+            task.priority
+        );
     }
 
     #[test]
@@ -1,4 +1,9 @@
 pub mod args;
+pub mod chart;
 pub mod commands;
+pub mod filter;
+pub mod format;
 pub mod metadata;
 pub mod run;
+pub mod style;
+pub mod target;
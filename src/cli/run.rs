@@ -1,6 +1,12 @@
-use crate::cli::args::Commands;
-use crate::cli::commands::{add_task, done_task, list_tasks, remove_task, update_task};
+use crate::cli::args::{Commands, ConfigAction, ReportKind, TagAction, TrackAction, ViewAction};
+use crate::cli::commands::{
+    add_task, agenda_overdue, show_board, show_calendar, config_get, config_path, config_set, config_show, config_unset, agenda_today, agenda_upcoming, delete_view, done_task, edit_bulk, list_tags, list_tasks, list_views, merge_tags, next_task, note_task, remove_task,
+    report_burndown, report_flow,
+    rename_tag, run_view, save_view, show_task, stats, timesheet, track_log, track_start, track_status, track_stop,
+    update_task,
+};
 use crate::repl::command_handler::ReplContext;
+use crate::tui::run_tui;
 
 pub fn execute_command(
     cmd: Commands,
@@ -8,9 +14,52 @@ pub fn execute_command(
 ) -> Result<(), Box<dyn std::error::Error>> {
     match cmd {
         Commands::Add { parent, text } => add_task(parent, text, context),
-        Commands::List => list_tasks(context),
-        Commands::Update { id, text } => update_task(id, text),
-        Commands::Remove { id } => remove_task(id),
-        Commands::Done { id } => done_task(id),
+        Commands::List { all, sort, filter } => list_tasks(&filter, all, sort, context),
+        Commands::Update { dry_run, yes, target } => update_task(&target, dry_run, yes, context),
+        Commands::Edit { bulk: _, markdown, yes, filter } => edit_bulk(&filter, markdown, yes, context),
+        Commands::Remove { dry_run, yes, target } => remove_task(&target, dry_run, yes, context),
+        Commands::Done { dry_run, yes, target } => done_task(&target, dry_run, yes, context),
+        Commands::Note { id, text } => note_task(id, &text, context),
+        Commands::Show { id } => show_task(id, context),
+        Commands::Tags => list_tags(context),
+        Commands::Tag { action } => match action {
+            TagAction::Rename { old, new } => rename_tag(&old, &new),
+            TagAction::Merge { sources, into } => merge_tags(&sources, &into),
+        },
+        Commands::View { action } => match action {
+            ViewAction::Save { name, query, global } => save_view(&name, &query, global, context),
+            ViewAction::List => list_views(context),
+            ViewAction::Delete { name } => delete_view(&name, context),
+            ViewAction::Run(args) => run_view(&args.join(" "), context),
+        },
+        Commands::Today => agenda_today(context),
+        Commands::Upcoming { days } => agenda_upcoming(days, context),
+        Commands::Overdue => agenda_overdue(context),
+        Commands::Track { action } => match action {
+            TrackAction::Start { id } => track_start(id, context),
+            TrackAction::Stop => track_stop(),
+            TrackAction::Log { id, duration } => track_log(id, &duration, context),
+            TrackAction::Status => track_status(),
+        },
+        Commands::Timesheet { by, from, to } => timesheet(by, from, to, context),
+        Commands::Stats { by, days, json } => stats(by, days, json, context),
+        Commands::Report { kind } => match kind {
+            ReportKind::Burndown(options) => report_burndown(options, context),
+            ReportKind::Flow(options) => report_flow(options, context),
+        },
+        Commands::Next => next_task(context),
+        Commands::Tui => run_tui(context),
+        Commands::Board { interactive } => show_board(interactive, context),
+        Commands::Calendar { month } => show_calendar(month, context),
+        Commands::Config { action } => match action {
+            None => config_show(),
+            Some(ConfigAction::Get { key }) => config_get(&key),
+            Some(ConfigAction::Set { key, value }) => config_set(&key, &value),
+            Some(ConfigAction::Unset { key }) => config_unset(&key),
+            Some(ConfigAction::Path) => {
+                config_path();
+                Ok(())
+            }
+        },
     }
 }
@@ -0,0 +1,126 @@
+use crate::config::{Config, ThemeConfig};
+use crate::task::model::Priority;
+use crate::task::Status;
+
+// Parse 'bold red' style words into an SGR parameter list such as "1;31"
+pub fn parse_style(style: &str) -> Result<String, String> {
+    let mut codes = Vec::new();
+    for word in style.split_whitespace() {
+        let code = match word.to_lowercase().as_str() {
+            "none" => continue,
+            "bold" => "1",
+            "dim" => "2",
+            "italic" => "3",
+            "underline" => "4",
+            "black" => "30",
+            "red" => "31",
+            "green" => "32",
+            "yellow" => "33",
+            "blue" => "34",
+            "magenta" => "35",
+            "cyan" => "36",
+            "white" => "37",
+            "gray" | "grey" => "90",
+            "bright_red" => "91",
+            "bright_green" => "92",
+            "bright_yellow" => "93",
+            "bright_blue" => "94",
+            "bright_magenta" => "95",
+            "bright_cyan" => "96",
+            "bright_white" => "97",
+            other => {
+                return Err(format!(
+                    "Unknown style '{}'. Use colors (red, bright_blue, gray, ...) and bold, dim, italic, underline",
+                    other
+                ))
+            }
+        };
+        codes.push(code);
+    }
+    Ok(codes.join(";"))
+}
+
+pub fn paint(text: &str, style: &str) -> String {
+    if style.is_empty() || text.is_empty() {
+        return text.to_string();
+    }
+    format!("\x1b[{}m{}\x1b[0m", style, text)
+}
+
+// Theme styles resolved to SGR codes; every style is empty when color is off
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    pub priority_urgent: String,
+    pub priority_high: String,
+    pub priority_medium: String,
+    pub priority_low: String,
+    pub status_todo: String,
+    pub status_in_progress: String,
+    pub status_done: String,
+    pub due: String,
+    pub overdue: String,
+    pub project: String,
+}
+
+impl Theme {
+    pub fn load() -> Self {
+        let config = Config::current();
+        if !config.display.use_color() {
+            return Theme::default();
+        }
+        Theme::from_config(&config.theme)
+    }
+
+    // Invalid styles are rejected when the config is loaded, so they only fall back here
+    pub fn from_config(theme: &ThemeConfig) -> Self {
+        let style = |s: &str| parse_style(s).unwrap_or_default();
+        Theme {
+            priority_urgent: style(&theme.priority_urgent),
+            priority_high: style(&theme.priority_high),
+            priority_medium: style(&theme.priority_medium),
+            priority_low: style(&theme.priority_low),
+            status_todo: style(&theme.status_todo),
+            status_in_progress: style(&theme.status_in_progress),
+            status_done: style(&theme.status_done),
+            due: style(&theme.due),
+            overdue: style(&theme.overdue),
+            project: style(&theme.project),
+        }
+    }
+
+    pub fn priority(&self, priority: &Priority) -> &str {
+        match priority {
+            Priority::Urgent => &self.priority_urgent,
+            Priority::High => &self.priority_high,
+            Priority::Medium => &self.priority_medium,
+            Priority::Low => &self.priority_low,
+        }
+    }
+
+    pub fn status(&self, status: &Status) -> &str {
+        match status {
+            Status::Todo => &self.status_todo,
+            Status::InProgress => &self.status_in_progress,
+            Status::Done => &self.status_done,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_style() {
+        assert_eq!(parse_style("bold red").unwrap(), "1;31");
+        assert_eq!(parse_style("").unwrap(), "");
+        assert_eq!(parse_style("none").unwrap(), "");
+        assert!(parse_style("sparkly").is_err());
+    }
+
+    #[test]
+    fn test_paint_skips_empty_style() {
+        assert_eq!(paint("!high", ""), "!high");
+        assert_eq!(paint("!high", "33"), "\x1b[33m!high\x1b[0m");
+    }
+}
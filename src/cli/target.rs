@@ -0,0 +1,163 @@
+use crate::cli::commands::list::find_matching_tasks;
+use crate::cli::filter::{parse_filter, TaskFilter};
+use crate::config::Config;
+use crate::repl::command_handler::ReplContext;
+use crate::task::{Task, TaskStore};
+use std::io::{self, BufRead, IsTerminal, Write};
+
+// A single range may not cover more IDs than this, to catch typos such as 1-10000
+const MAX_RANGE: u32 = 1000;
+
+// The tasks a command applies to: explicit IDs or everything matching a slash filter
+#[derive(Debug)]
+pub enum TaskTarget {
+    Ids(Vec<u32>),
+    Filter(TaskFilter),
+}
+
+const TARGET_USAGE: &str = "Give task IDs such as '3', '3,5,7-9', or a filter such as '/tag sprint12'";
+
+// For commands that take nothing but a target: 'done 3 5 7-9' works like 'done 3,5,7-9'
+pub fn parse_whole_target(args: &[String]) -> Result<TaskTarget, String> {
+    match args.first() {
+        None => Err(TARGET_USAGE.to_string()),
+        Some(first) if first.starts_with('/') => Ok(TaskTarget::Filter(parse_filter(&args.join(" "))?)),
+        Some(_) => Ok(TaskTarget::Ids(parse_id_list(&args.join(","))?)),
+    }
+}
+
+// Split command arguments into the target and the words after it: IDs take one word
+// ('3,5,7-9'), a filter runs up to '--'
+pub fn parse_target(args: &[String]) -> Result<(TaskTarget, Vec<String>), String> {
+    let first = args.first().ok_or(TARGET_USAGE)?;
+
+    if !first.starts_with('/') {
+        let rest = match args.get(1).map(String::as_str) {
+            Some("--") => args[2..].to_vec(),
+            _ => args[1..].to_vec(),
+        };
+        return Ok((TaskTarget::Ids(parse_id_list(first)?), rest));
+    }
+
+    let separator = args
+        .iter()
+        .position(|arg| arg == "--")
+        .ok_or("Separate the filter from the changes with '--', e.g. 'update /tag sprint12 -- /p high'")?;
+    let filter = parse_filter(&args[..separator].join(" "))?;
+    Ok((TaskTarget::Filter(filter), args[separator + 1..].to_vec()))
+}
+
+// '3,5,7-9' -> [3, 5, 7, 8, 9], keeping the first occurrence of repeated IDs
+pub fn parse_id_list(input: &str) -> Result<Vec<u32>, String> {
+    let mut ids = Vec::new();
+    for part in input.split(',').map(str::trim) {
+        let invalid = || format!("Invalid task ID or range: '{}'. Use e.g. 3,5,7-9", part);
+        let (start, end) = match part.split_once('-') {
+            Some((start, end)) => (start.trim().parse::<u32>().map_err(|_| invalid())?, end.trim().parse::<u32>().map_err(|_| invalid())?),
+            None => {
+                let id = part.parse::<u32>().map_err(|_| invalid())?;
+                (id, id)
+            }
+        };
+        if start > end || end - start >= MAX_RANGE {
+            return Err(invalid());
+        }
+        for id in start..=end {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    Ok(ids)
+}
+
+// Load the targeted tasks; missing IDs are reported and skipped
+pub fn resolve_target(store: &TaskStore, target: &TaskTarget, context: &ReplContext) -> Result<Vec<Task>, Box<dyn std::error::Error>> {
+    match target {
+        TaskTarget::Ids(ids) => {
+            let mut tasks = Vec::new();
+            for &id in ids {
+                match store.find_by_id(id)? {
+                    Some(task) => tasks.push(task),
+                    None => context.report(format!("Task with ID {} not found.", id))?,
+                }
+            }
+            Ok(tasks)
+        }
+        TaskTarget::Filter(filter) => {
+            let tasks = find_matching_tasks(store, filter, context)?;
+            if tasks.is_empty() {
+                println!("No tasks found.");
+            }
+            Ok(tasks)
+        }
+    }
+}
+
+// Ask before changing more than bulk.confirm_over tasks, given as (id, title);
+// scripts must pass --yes instead
+pub fn confirm_bulk<'a>(
+    action: &str,
+    tasks: impl ExactSizeIterator<Item = (u32, &'a str)>,
+    yes: bool,
+    context: &ReplContext,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let threshold = Config::current().bulk.confirm_over;
+    let count = tasks.len();
+    if yes || count <= threshold {
+        return Ok(true);
+    }
+    if context.strict || !io::stdin().is_terminal() {
+        return Err(format!("Refusing to {} {} tasks without confirmation; add --yes", action, count).into());
+    }
+
+    for (id, title) in tasks {
+        println!("  [{}] {}", id, title);
+    }
+    print!("This will {} {} tasks. Continue? [y/N] ", action, count);
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    let confirmed = matches!(answer.trim().to_lowercase().as_str(), "y" | "yes");
+    if !confirmed {
+        println!("Cancelled. No tasks were changed.");
+    }
+    Ok(confirmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_id_list() {
+        assert_eq!(parse_id_list("3").unwrap(), vec![3]);
+        assert_eq!(parse_id_list("3,5,7-9").unwrap(), vec![3, 5, 7, 8, 9]);
+        assert_eq!(parse_id_list("2,1-3").unwrap(), vec![2, 1, 3]);
+        assert!(parse_id_list("9-7").is_err());
+        assert!(parse_id_list("3,,5").is_err());
+        assert!(parse_id_list("1-5000").is_err());
+        assert!(parse_id_list("abc").is_err());
+    }
+
+    #[test]
+    fn test_parse_target() {
+        let (target, rest) = parse_target(&args(&["3,4", "/p", "high"])).unwrap();
+        assert!(matches!(target, TaskTarget::Ids(ids) if ids == vec![3, 4]));
+        assert_eq!(rest, args(&["/p", "high"]));
+
+        let (target, rest) = parse_target(&args(&["/tag", "sprint12", "--", "/p", "high"])).unwrap();
+        assert!(matches!(target, TaskTarget::Filter(filter) if filter.tags == vec!["sprint12"]));
+        assert_eq!(rest, args(&["/p", "high"]));
+
+        assert!(parse_target(&args(&["/tag", "sprint12", "/p", "high"])).is_err());
+
+        assert!(matches!(parse_whole_target(&args(&["3", "5-6"])).unwrap(), TaskTarget::Ids(ids) if ids == vec![3, 5, 6]));
+        assert!(matches!(parse_whole_target(&args(&["/status", "todo"])).unwrap(), TaskTarget::Filter(_)));
+    }
+}
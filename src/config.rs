@@ -0,0 +1,343 @@
+use crate::cli::style::parse_style;
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::fs;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+static CURRENT: Mutex<Option<Arc<Config>>> = Mutex::new(None);
+
+// User settings from config.toml in the platform config dir; every key is optional
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub defaults: DefaultsConfig,
+    pub display: DisplayConfig,
+    pub theme: ThemeConfig,
+    pub urgency: UrgencyConfig,
+    pub history: HistoryConfig,
+    pub bulk: BulkConfig,
+    // name = "command" or a macro "command; command"
+    pub aliases: BTreeMap<String, String>,
+}
+
+// REPL history; per_project keeps a separate file for each '/use' project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HistoryConfig {
+    pub size: usize,
+    pub ignore_dups: bool,
+    pub per_project: bool,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        HistoryConfig {
+            size: 1000,
+            ignore_dups: true,
+            per_project: false,
+        }
+    }
+}
+
+// done, remove and update ask before touching more than confirm_over tasks at once
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BulkConfig {
+    pub confirm_over: usize,
+}
+
+impl Default for BulkConfig {
+    fn default() -> Self {
+        BulkConfig { confirm_over: 5 }
+    }
+}
+
+// Values used for new tasks; project applies when no project is in use
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DefaultsConfig {
+    pub priority: String,
+    pub status: String,
+    pub project: Option<String>,
+    // Used when $EDITOR is not set
+    pub editor: String,
+}
+
+impl Default for DefaultsConfig {
+    fn default() -> Self {
+        DefaultsConfig {
+            priority: "medium".to_string(),
+            status: "todo".to_string(),
+            project: None,
+            editor: "vim".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    pub date_format: String,
+    pub datetime_format: String,
+    pub glyphs: Glyphs,
+    pub color: ColorMode,
+    pub week_start: WeekStart,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        DisplayConfig {
+            date_format: "%Y-%m-%d".to_string(),
+            datetime_format: "%Y-%m-%d %H:%M".to_string(),
+            glyphs: Glyphs::Emoji,
+            color: ColorMode::Auto,
+            week_start: WeekStart::Monday,
+        }
+    }
+}
+
+impl DisplayConfig {
+    // Auto follows the NO_COLOR convention and only colors a terminal
+    pub fn use_color(&self) -> bool {
+        match self.color {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal(),
+        }
+    }
+}
+
+// Styles for task lines: color names plus bold, dim, italic or underline, e.g. "bold red"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub priority_urgent: String,
+    pub priority_high: String,
+    pub priority_medium: String,
+    pub priority_low: String,
+    pub status_todo: String,
+    pub status_in_progress: String,
+    pub status_done: String,
+    pub due: String,
+    pub overdue: String,
+    pub project: String,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        ThemeConfig {
+            priority_urgent: "bold red".to_string(),
+            priority_high: "yellow".to_string(),
+            priority_medium: String::new(),
+            priority_low: "dim".to_string(),
+            status_todo: String::new(),
+            status_in_progress: "cyan".to_string(),
+            status_done: "green".to_string(),
+            due: "blue".to_string(),
+            overdue: "bold red".to_string(),
+            project: "magenta".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Glyphs {
+    Emoji,
+    Ascii,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekStart {
+    Monday,
+    Sunday,
+}
+
+impl WeekStart {
+    pub fn weekday(self) -> Weekday {
+        match self {
+            WeekStart::Monday => Weekday::Mon,
+            WeekStart::Sunday => Weekday::Sun,
+        }
+    }
+
+    pub fn start_of_week(self, day: NaiveDate) -> NaiveDate {
+        day - Duration::days(day.weekday().days_since(self.weekday()) as i64)
+    }
+}
+
+// Coefficients for the urgency score: each factor is scaled to 0..1 and multiplied
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrgencyConfig {
+    pub priority_urgent: f64,
+    pub priority_high: f64,
+    pub priority_medium: f64,
+    pub priority_low: f64,
+    pub due: f64,
+    pub age: f64,
+    pub in_progress: f64,
+    pub blocked: f64,
+    pub blocking: f64,
+    pub tags: HashMap<String, f64>,
+    pub projects: HashMap<String, f64>,
+}
+
+impl Default for UrgencyConfig {
+    fn default() -> Self {
+        UrgencyConfig {
+            priority_urgent: 9.0,
+            priority_high: 6.0,
+            priority_medium: 3.9,
+            priority_low: 1.8,
+            due: 12.0,
+            age: 2.0,
+            in_progress: 4.0,
+            blocked: -5.0,
+            blocking: 1.0,
+            tags: HashMap::from([("next".to_string(), 15.0)]),
+            projects: HashMap::new(),
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        ProjectDirs::from("com", "you", "clara")
+            .expect("no valid home dir")
+            .config_dir()
+            .join("config.toml")
+    }
+
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let content = fs::read_to_string(&path)?;
+        let config: Config =
+            toml::from_str(&content).map_err(|e| format!("Invalid config file {}: {}", path.display(), e))?;
+        config
+            .validate()
+            .map_err(|e| format!("Invalid config file {}: {}", path.display(), e))?;
+        Ok(config)
+    }
+
+    // Loaded once and shared; an invalid file is reported and the defaults are used instead
+    pub fn current() -> Arc<Config> {
+        let mut current = CURRENT.lock().expect("config lock poisoned");
+        current
+            .get_or_insert_with(|| {
+                Arc::new(Config::load().unwrap_or_else(|e| {
+                    eprintln!("⚠️  {}", e);
+                    Config::default()
+                }))
+            })
+            .clone()
+    }
+
+    // Pick up changes written by 'config set'
+    pub fn reload() {
+        *CURRENT.lock().expect("config lock poisoned") = None;
+    }
+
+    // Checks the values serde cannot: names from fixed lists and date formats
+    pub fn validate(&self) -> Result<(), String> {
+        if !matches!(self.defaults.priority.as_str(), "low" | "medium" | "high" | "urgent") {
+            return Err(format!(
+                "defaults.priority '{}' is invalid. Use: low, medium, high, or urgent",
+                self.defaults.priority
+            ));
+        }
+        if !matches!(self.defaults.status.as_str(), "todo" | "in_progress" | "done") {
+            return Err(format!(
+                "defaults.status '{}' is invalid. Use: todo, in_progress, or done",
+                self.defaults.status
+            ));
+        }
+        // chrono reports an unknown specifier, or a time specifier on a plain date, as a
+        // formatting error; sample the same types format_date and format_datetime use
+        let date = NaiveDate::from_ymd_opt(2000, 1, 31).expect("valid date");
+        if write!(String::new(), "{}", date.format(&self.display.date_format)).is_err() {
+            return Err(format!("display.date_format '{}' is not a valid date format", self.display.date_format));
+        }
+        let time = Local::now();
+        if write!(String::new(), "{}", time.format(&self.display.datetime_format)).is_err() {
+            return Err(format!(
+                "display.datetime_format '{}' is not a valid date format",
+                self.display.datetime_format
+            ));
+        }
+
+        let theme = toml::Table::try_from(&self.theme).map_err(|e| e.to_string())?;
+        for (key, style) in &theme {
+            if let Some(style) = style.as_str() {
+                parse_style(style).map_err(|e| format!("theme.{}: {}", key, e))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_config_keeps_defaults() {
+        let config: Config = toml::from_str(
+            "[urgency]\ndue = 20.0\n\n[urgency.projects]\nwork = 2.5\n",
+        )
+        .unwrap();
+        assert_eq!(config.urgency.due, 20.0);
+        assert_eq!(config.urgency.priority_high, 6.0);
+        assert_eq!(config.urgency.projects.get("work"), Some(&2.5));
+        assert_eq!(config.history.size, 1000);
+        assert_eq!(config.urgency.tags.get("next"), Some(&15.0));
+        assert_eq!(config.defaults.priority, "medium");
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_values() {
+        let config: Config = toml::from_str("[display]\ndate_format = \"%d.%m.%Y\"\nweek_start = \"sunday\"\n").unwrap();
+        assert!(config.validate().is_ok());
+
+        let config: Config = toml::from_str("[defaults]\npriority = \"huge\"\n").unwrap();
+        assert!(config.validate().is_err());
+
+        let config: Config = toml::from_str("[display]\ndate_format = \"%Q\"\n").unwrap();
+        assert!(config.validate().is_err());
+
+        // Dates have no time of day to format
+        let config: Config = toml::from_str("[display]\ndate_format = \"%d.%m %H:%M\"\n").unwrap();
+        assert!(config.validate().is_err());
+        let config: Config = toml::from_str("[display]\ndatetime_format = \"%d.%m %H:%M\"\n").unwrap();
+        assert!(config.validate().is_ok());
+
+        let config: Config = toml::from_str("[theme]\noverdue = \"sparkly\"\n").unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_start_of_week() {
+        // 2026-10-18 is a Sunday
+        let day = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        assert_eq!(WeekStart::Monday.start_of_week(day), NaiveDate::from_ymd_opt(2026, 10, 12).unwrap());
+        assert_eq!(WeekStart::Sunday.start_of_week(day), day);
+    }
+}
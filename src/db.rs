@@ -0,0 +1,48 @@
+use rusqlite::{Connection, Result};
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
+
+thread_local! {
+    // Set while a transactional script runs, so every store works inside its transaction
+    static SHARED: RefCell<Option<Rc<Connection>>> = const { RefCell::new(None) };
+}
+
+// The shared connection when there is one, otherwise a new connection to the database file
+pub fn connect(path: &Path) -> Result<Rc<Connection>> {
+    if let Some(conn) = SHARED.with_borrow(|shared| shared.clone()) {
+        return Ok(conn);
+    }
+    Ok(Rc::new(Connection::open(path)?))
+}
+
+// Open a transaction that every store opened afterwards takes part in, until it is finished
+pub fn begin_shared(path: &Path) -> Result<()> {
+    let conn = connect(path)?;
+    conn.execute_batch("BEGIN")?;
+    SHARED.with_borrow_mut(|shared| *shared = Some(conn));
+    Ok(())
+}
+
+pub fn finish_shared(commit: bool) -> Result<()> {
+    match SHARED.with_borrow_mut(Option::take) {
+        Some(conn) => conn.execute_batch(if commit { "COMMIT" } else { "ROLLBACK" }),
+        None => Ok(()),
+    }
+}
+
+// Run several writes as one unit; a savepoint rather than BEGIN, so this also works
+// inside a script's transaction
+pub fn atomically<T>(conn: &Connection, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    conn.execute_batch("SAVEPOINT atomically")?;
+    match f() {
+        Ok(value) => {
+            conn.execute_batch("RELEASE atomically")?;
+            Ok(value)
+        }
+        Err(e) => {
+            conn.execute_batch("ROLLBACK TO atomically; RELEASE atomically")?;
+            Err(e)
+        }
+    }
+}
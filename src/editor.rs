@@ -1,8 +1,12 @@
+use crate::config::Config;
 use std::env;
 use std::fs;
 use std::process::Command;
 use tempfile::NamedTempFile;
 
+// Marks the lines we add to report an error, so they can be removed again
+const ERROR_PREFIX: &str = "# !! ";
+
 pub fn edit_toml_content(initial_content: &str) -> Result<String, Box<dyn std::error::Error>> {
     // Create a temporary file
     let temp_file = NamedTempFile::new()?;
@@ -10,8 +14,8 @@ pub fn edit_toml_content(initial_content: &str) -> Result<String, Box<dyn std::e
     // Write initial content to temp file
     fs::write(temp_file.path(), initial_content)?;
     
-    // Get editor from environment variable, default to vim
-    let editor = env::var("EDITOR").unwrap_or_else(|_| "vim".to_string());
+    // Get editor from environment variable, falling back to defaults.editor
+    let editor = env::var("EDITOR").unwrap_or_else(|_| Config::current().defaults.editor.clone());
     
     // Open editor
     let status = Command::new(&editor)
@@ -26,4 +30,75 @@ pub fn edit_toml_content(initial_content: &str) -> Result<String, Box<dyn std::e
     let edited_content = fs::read_to_string(temp_file.path())?;
     
     Ok(edited_content)
-}
\ No newline at end of file
+}
+
+// Reopen the editor until `parse` accepts the content, showing the last error as a comment
+// at the top. Returns None when the user empties the file to give up.
+pub fn edit_until_valid<T>(
+    initial_content: &str,
+    mut parse: impl FnMut(&str) -> Result<T, String>,
+) -> Result<Option<T>, Box<dyn std::error::Error>> {
+    let mut content = initial_content.to_string();
+    loop {
+        let edited = strip_error_comment(&edit_toml_content(&content)?);
+        if is_blank(&edited) {
+            return Ok(None);
+        }
+
+        match parse(&edited) {
+            Ok(value) => return Ok(Some(value)),
+            Err(e) => content = with_error_comment(&edited, &e),
+        }
+    }
+}
+
+fn with_error_comment(content: &str, error: &str) -> String {
+    let mut annotated = String::new();
+    for line in error.trim_end().lines() {
+        annotated.push_str(format!("{}{}", ERROR_PREFIX, line).trim_end());
+        annotated.push('\n');
+    }
+    annotated.push_str(&format!("{}Fix it and save, or delete everything to cancel.\n", ERROR_PREFIX));
+    annotated.push_str(content);
+    annotated
+}
+
+fn strip_error_comment(content: &str) -> String {
+    let kept: Vec<&str> = content
+        .lines()
+        .skip_while(|line| line.starts_with(ERROR_PREFIX.trim_end()))
+        .collect();
+    let mut stripped = kept.join("\n");
+    if content.ends_with('\n') && !stripped.is_empty() {
+        stripped.push('\n');
+    }
+    stripped
+}
+
+// Only comments and whitespace left
+fn is_blank(content: &str) -> bool {
+    content
+        .lines()
+        .map(str::trim)
+        .all(|line| line.is_empty() || line.starts_with('#'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_comment_round_trip() {
+        let content = "title = \"\"\nstatus = \"todo\"\n";
+        let annotated = with_error_comment(content, "Task title cannot be empty");
+        assert!(annotated.starts_with("# !! Task title cannot be empty\n"));
+        assert_eq!(strip_error_comment(&annotated), content);
+    }
+
+    #[test]
+    fn test_comment_only_content_is_blank() {
+        assert!(is_blank("\n  \n"));
+        assert!(is_blank("# Valid statuses: todo\n\n"));
+        assert!(!is_blank("# note\ntitle = \"x\"\n"));
+    }
+}
@@ -1,12 +1,50 @@
 mod cli;
+mod config;
+mod db;
 mod editor;
 mod project;
 mod repl; 
 mod task;
+mod timelog;
+mod tui;
+mod view;
 
+use clap::Parser;
+use cli::{args::Cli, run::execute_command};
+use repl::command_handler::ReplContext;
+use repl::script::{run_script, ScriptOptions};
 use repl::start_repl;
 
 fn main() {
+    // 'clara -' is shorthand for '--script -'
+    let mut args: Vec<String> = std::env::args().collect();
+    if args.get(1).is_some_and(|arg| arg == "-") {
+        args[1] = "--script=-".to_string();
+    }
+    let cli = Cli::parse_from(args);
+
+    if let Some(source) = cli.script {
+        let options = ScriptOptions {
+            stop_on_error: cli.stop_on_error,
+            transactional: cli.transactional,
+        };
+        if let Err(e) = run_script(&source, &options) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Run a single command when one is given, otherwise start the REPL
+    if let Some(cmd) = cli.cmd {
+        let context = ReplContext::new();
+        if let Err(e) = execute_command(cmd, &context) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     if let Err(e) = start_repl() {
         eprintln!("Error starting REPL: {}", e);
         std::process::exit(1);
@@ -45,7 +45,7 @@ impl ProjectDraft {
         }
     }
 
-    pub fn to_project(self) -> Result<Project, String> {
+    pub fn into_project(self) -> Result<Project, String> {
         if self.name.trim().is_empty() {
             return Err("Project name cannot be empty".to_string());
         }
@@ -76,10 +76,12 @@ impl Project {
         Local.timestamp_opt(self.created_at, 0).unwrap()
     }
     
+    #[allow(dead_code)]
     pub fn updated_at_datetime(&self) -> DateTime<Local> {
         Local.timestamp_opt(self.updated_at, 0).unwrap()
     }
 
+    #[allow(dead_code)]
     pub fn to_draft(&self) -> ProjectDraft {
         ProjectDraft {
             name: self.name.clone(),
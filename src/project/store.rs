@@ -1,16 +1,18 @@
+use crate::db;
 use crate::project::model::Project;
 use directories::ProjectDirs;
 use rusqlite::{Connection, Result};
 use std::path::PathBuf;
+use std::rc::Rc;
 
 pub struct ProjectStore {
-    conn: Connection,
+    conn: Rc<Connection>,
 }
 
 impl ProjectStore {
     pub fn new() -> Result<Self> {
         let db_path = Self::get_db_path();
-        let conn = Connection::open(db_path)?;
+        let conn = db::connect(&db_path)?;
 
         let store = ProjectStore { conn };
         store.init_tables()?;
@@ -128,6 +130,7 @@ impl ProjectStore {
         }
     }
 
+    #[allow(dead_code)]
     pub fn update(&self, project: &Project) -> Result<()> {
         let extras_json = project
             .extras
@@ -147,6 +150,7 @@ impl ProjectStore {
         Ok(())
     }
 
+    #[allow(dead_code)]
     pub fn delete(&self, id: u32) -> Result<()> {
         self.conn
             .execute("DELETE FROM projects WHERE id = ?1", rusqlite::params![id])?;
@@ -0,0 +1,149 @@
+use crate::config::Config;
+use crate::repl::command_handler::ReplContext;
+use std::collections::BTreeMap;
+
+// Deep enough for aliases built on aliases, shallow enough to stop a cycle quickly
+const MAX_DEPTH: usize = 10;
+
+// Replace a leading alias with its commands; extra words go to the end of the last one
+pub fn expand_aliases(input: &str, aliases: &BTreeMap<String, String>) -> Result<Vec<String>, String> {
+    expand(input.trim(), aliases, &mut Vec::new())
+}
+
+// `path` holds the aliases being expanded, outermost first
+fn expand<'a>(input: &str, aliases: &'a BTreeMap<String, String>, path: &mut Vec<&'a str>) -> Result<Vec<String>, String> {
+    let (name, rest) = match input.split_once(char::is_whitespace) {
+        Some((name, rest)) => (name, rest.trim()),
+        None => (input, ""),
+    };
+
+    let (name, expansion) = match aliases.get_key_value(name) {
+        Some((name, expansion)) => (name.as_str(), expansion),
+        None => return Ok(vec![input.to_string()]),
+    };
+    // An alias using its own name, such as 'list = list --all', means the builtin command
+    if path.last() == Some(&name) {
+        return Ok(vec![input.to_string()]);
+    }
+    if path.contains(&name) || path.len() >= MAX_DEPTH {
+        return Err(format!("Alias '{}' expands too deeply; check for a cycle", name));
+    }
+
+    let mut commands: Vec<String> = split_commands(expansion).map(str::to_string).collect();
+    if let Some(last) = commands.last_mut()
+        && !rest.is_empty()
+    {
+        last.push(' ');
+        last.push_str(rest);
+    }
+
+    path.push(name);
+    let mut expanded = Vec::new();
+    for command in commands {
+        expanded.extend(expand(&command, aliases, path)?);
+    }
+    path.pop();
+    Ok(expanded)
+}
+
+// A macro is several commands separated by ';'
+fn split_commands(expansion: &str) -> impl Iterator<Item = &str> {
+    expansion.split(';').map(str::trim).filter(|c| !c.is_empty())
+}
+
+// 'name = command; command'
+pub fn parse_alias_definition(input: &str) -> Result<(String, String), String> {
+    let (name, expansion) = input
+        .split_once('=')
+        .ok_or_else(|| "Usage: /alias <name> = <command>[; <command>...]".to_string())?;
+    let name = name.trim();
+    let expansion = expansion.trim();
+
+    if name.is_empty() || name.contains(char::is_whitespace) || name.starts_with('/') {
+        return Err(format!("Invalid alias name: '{}'. Use a single word without a leading '/'", name));
+    }
+    if split_commands(expansion).next().is_none() {
+        return Err(format!("Alias '{}' needs at least one command", name));
+    }
+
+    Ok((name.to_string(), expansion.to_string()))
+}
+
+pub fn handle_alias_command(args: &str, context: &mut ReplContext) {
+    let args = args.trim();
+
+    if args.is_empty() {
+        if context.aliases.is_empty() {
+            println!("No aliases defined. Add one with '/alias <name> = <command>'.");
+            return;
+        }
+        println!("🔗 Aliases:");
+        let width = context.aliases.keys().map(|name| name.chars().count()).max().unwrap_or(0);
+        for (name, expansion) in &context.aliases {
+            println!("  {:<width$} = {}", name, expansion, width = width);
+        }
+        return;
+    }
+
+    if let Some(name) = args.strip_prefix("--remove ").map(str::trim) {
+        match context.aliases.remove(name) {
+            Some(_) => println!("🗑️  Alias '{}' removed for this session.", name),
+            None => println!("❌ Alias '{}' not found.", name),
+        }
+        return;
+    }
+
+    match parse_alias_definition(args) {
+        Ok((name, expansion)) => {
+            println!("✅ Alias '{}' = {}", name, expansion);
+            println!("(Add it under [aliases] in {} to keep it)", Config::path().display());
+            context.aliases.insert(name, expansion);
+        }
+        Err(e) => println!("❌ {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alias_map(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_expand_alias_with_arguments() {
+        let aliases = alias_map(&[("d", "done"), ("hot", "list /p urgent /status todo")]);
+        assert_eq!(expand_aliases("d 3", &aliases).unwrap(), vec!["done 3"]);
+        assert_eq!(expand_aliases("hot", &aliases).unwrap(), vec!["list /p urgent /status todo"]);
+        assert_eq!(expand_aliases("done 3", &aliases).unwrap(), vec!["done 3"]);
+    }
+
+    #[test]
+    fn test_expand_macro_and_nested_aliases() {
+        let aliases = alias_map(&[("hot", "list /p urgent"), ("morning", "today; hot; next")]);
+        assert_eq!(expand_aliases("morning", &aliases).unwrap(), vec!["today", "list /p urgent", "next"]);
+
+        let cyclic = alias_map(&[("a", "b"), ("b", "a")]);
+        assert!(expand_aliases("a", &cyclic).is_err());
+    }
+
+    #[test]
+    fn test_alias_shadowing_its_command() {
+        let aliases = alias_map(&[("list", "list --all"), ("morning", "list; today")]);
+        assert_eq!(expand_aliases("list /tag work", &aliases).unwrap(), vec!["list --all /tag work"]);
+        assert_eq!(expand_aliases("morning", &aliases).unwrap(), vec!["list --all", "today"]);
+    }
+
+    #[test]
+    fn test_parse_alias_definition() {
+        assert_eq!(
+            parse_alias_definition("hot = list /p urgent; next").unwrap(),
+            ("hot".to_string(), "list /p urgent; next".to_string())
+        );
+        assert!(parse_alias_definition("two words = list").is_err());
+        assert!(parse_alias_definition("/x = list").is_err());
+        assert!(parse_alias_definition("empty = ;").is_err());
+        assert!(parse_alias_definition("hot").is_err());
+    }
+}
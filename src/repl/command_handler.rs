@@ -1,6 +1,11 @@
 use crate::cli::{run::execute_command, args::Cli};
 use crate::project::{Project, ProjectStore};
+use crate::repl::alias::{expand_aliases, handle_alias_command};
+use crate::repl::pomodoro::{handle_pomodoro_command, Pomodoro};
+use chrono::{Local, NaiveDate};
 use clap::Parser;
+use std::cell::Cell;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone)]
 pub enum Context {
@@ -12,6 +17,12 @@ pub enum Context {
 pub struct ReplContext {
     pub mode: Context,
     pub current_project: Option<Project>,
+    pub pomodoro: Option<Pomodoro>,
+    // Month shown by the last 'calendar', so 'calendar next' can step from it
+    pub calendar_month: Cell<Option<NaiveDate>>,
+    pub aliases: BTreeMap<String, String>,
+    // Set for scripts: mistakes the REPL only reports become errors
+    pub strict: bool,
 }
 
 impl ReplContext {
@@ -19,11 +30,15 @@ impl ReplContext {
         ReplContext {
             mode: Context::Task,
             current_project: None,
+            pomodoro: None,
+            calendar_month: Cell::new(None),
+            aliases: BTreeMap::new(),
+            strict: false,
         }
     }
 
     pub fn get_prompt(&self) -> String {
-        match &self.mode {
+        let prompt = match &self.mode {
             Context::Task => {
                 if let Some(project) = &self.current_project {
                     format!("task@{} > ", project.name)
@@ -32,6 +47,11 @@ impl ReplContext {
                 }
             }
             Context::Project => "project > ".to_string(),
+        };
+
+        match &self.pomodoro {
+            Some(pomodoro) => format!("{}{}", pomodoro.prompt_prefix(Local::now().timestamp()), prompt),
+            None => prompt,
         }
     }
 
@@ -46,6 +66,15 @@ impl ReplContext {
     pub fn set_current_project(&mut self, project: Option<Project>) {
         self.current_project = project;
     }
+
+    // Print a failed command's reason, or in strict mode return it as the error
+    pub fn report(&self, message: String) -> Result<(), Box<dyn std::error::Error>> {
+        if self.strict {
+            return Err(message.into());
+        }
+        println!("❌ {}", message);
+        Ok(())
+    }
 }
 
 pub fn handle_repl_command(input: &str, context: &mut ReplContext) -> std::result::Result<bool, Box<dyn std::error::Error>> {
@@ -58,11 +87,23 @@ pub fn handle_repl_command(input: &str, context: &mut ReplContext) -> std::resul
     if trimmed.is_empty() {
         return Ok(true);
     }
-    
-    match context.mode {
-        Context::Task => handle_task_command(input, context),
-        Context::Project => handle_project_command(input, context),
+
+    // An alias can expand to several commands, including REPL ones; they run in order
+    for command in expand_aliases(trimmed, &context.aliases)? {
+        let should_continue = if command.starts_with('/') {
+            handle_internal_command(&command, context)?
+        } else {
+            match context.mode {
+                Context::Task => handle_task_command(&command, context)?,
+                Context::Project => handle_project_command(&command, context)?,
+            }
+        };
+        if !should_continue {
+            return Ok(false);
+        }
     }
+
+    Ok(true)
 }
 
 fn handle_internal_command(command: &str, context: &mut ReplContext) -> std::result::Result<bool, Box<dyn std::error::Error>> {
@@ -101,6 +142,9 @@ fn handle_internal_command(command: &str, context: &mut ReplContext) -> std::res
                     context.set_current_project(Some(project));
                     context.switch_to_task_mode();
                 }
+                None if context.strict => {
+                    return Err(format!("Project '{}' not found", project_name).into());
+                }
                 None => {
                     println!("Project '{}' not found. Use 'project list' to see available projects.", project_name);
                 }
@@ -113,9 +157,18 @@ fn handle_internal_command(command: &str, context: &mut ReplContext) -> std::res
             println!("Cleared project context. Now in global task mode.");
             Ok(true)
         }
+        "/pomodoro" => {
+            handle_pomodoro_command(&parts[1..], context)?;
+            Ok(true)
+        }
+        "/alias" => {
+            handle_alias_command(&command["/alias".len()..], context);
+            Ok(true)
+        }
+        _ if context.strict => Err(format!("Unknown REPL command: {}", command).into()),
         _ => {
             println!("Unknown REPL command: {}", command);
-            println!("Available commands: /help, /quit, /project, /task, /use <project>, /clear");
+            println!("Available commands: /help, /quit, /project, /task, /use <project>, /clear, /pomodoro, /history, /alias");
             Ok(true)
         }
     }
@@ -140,38 +193,6 @@ fn handle_task_command(input: &str, context: &ReplContext) -> std::result::Resul
         }
     }
     
-    // Special handling for 'update' command with slash metadata
-    if trimmed_input.starts_with("update ") && !trimmed_input.contains("--") {
-        // For update with slash metadata, we need to be more careful about parsing
-        // Expected formats:
-        // 1. update 5 /p urgent           (metadata-only)
-        // 2. update 5 "New title" /p urgent  (title + metadata, quoted)
-        // 3. update 5 SingleWord /p urgent   (single word title + metadata)
-        
-        // Use shell_words to properly handle quoted arguments
-        match shell_words::split(trimmed_input) {
-            Ok(argv) if argv.len() >= 3 => {
-                if let Ok(id) = argv[1].parse::<u32>() {
-                    // Check if there's slash metadata anywhere in the remaining args
-                    let remaining_args = &argv[2..];
-                    if remaining_args.iter().any(|arg| arg.starts_with('/')) {
-                        // Found slash metadata, reconstruct the text part
-                        let text = remaining_args.join(" ");
-                        
-                        use crate::cli::args::Commands;
-                        let cmd = Commands::Update {
-                            id,
-                            text: Some(text)
-                        };
-                        execute_command(cmd, context)?;
-                        return Ok(true);
-                    }
-                }
-            }
-            _ => {} // Fall through to normal parsing
-        }
-    }
-
     // Fall back to normal shell parsing for other commands
     let argv = match shell_words::split(input) {
         Ok(v) if v.is_empty() => return Ok(true),
@@ -192,6 +213,9 @@ fn handle_task_command(input: &str, context: &ReplContext) -> std::result::Resul
                 print_task_help();
             }
         }
+        Err(err) if context.strict && err.use_stderr() => {
+            return Err(err.render().to_string().trim_end().into());
+        }
         Err(err) => {
             err.print()?;
         }
@@ -200,7 +224,7 @@ fn handle_task_command(input: &str, context: &ReplContext) -> std::result::Resul
     Ok(true)
 }
 
-fn handle_project_command(input: &str, _context: &ReplContext) -> std::result::Result<bool, Box<dyn std::error::Error>> {
+fn handle_project_command(input: &str, context: &ReplContext) -> std::result::Result<bool, Box<dyn std::error::Error>> {
     let argv = match shell_words::split(input) {
         Ok(v) if v.is_empty() => return Ok(true),
         Ok(v) => v,
@@ -230,27 +254,40 @@ fn handle_project_command(input: &str, _context: &ReplContext) -> std::result::R
         }
         "add" => {
             use crate::project::{ProjectDraft, ProjectStore};
-            use crate::editor::edit_toml_content;
+            use crate::editor::edit_until_valid;
             use rusqlite::{Error as SqliteError, ErrorCode};
             
-            // Create TOML template and edit using shared editor utility
-            let template = ProjectDraft::new();
-            let toml_content = template.to_toml()?;
-            let edited_content = match edit_toml_content(&toml_content) {
-                Ok(content) => content,
-                Err(_) => {
-                    println!("❌ Editor exited with non-zero status");
-                    return Ok(true);
+            // 'add <name> [description]' skips the editor, which scripts cannot use
+            let project = if let Some(name) = argv.get(1) {
+                let draft = ProjectDraft {
+                    name: name.clone(),
+                    description: Some(argv[2..].join(" ")),
+                    ..ProjectDraft::new()
+                };
+                draft.into_project()?
+            } else {
+                // Create TOML template and edit using shared editor utility
+                let template = ProjectDraft::new();
+                let toml_content = template.to_toml()?;
+                let parse_project = |content: &str| {
+                    ProjectDraft::from_toml(content)
+                        .map_err(|e| format!("Invalid TOML: {}", e))?
+                        .into_project()
+                };
+                match edit_until_valid(&toml_content, parse_project) {
+                    Ok(Some(project)) => project,
+                    Ok(None) => {
+                        println!("❌ Cancelled: the file was left empty.");
+                        return Ok(true);
+                    }
+                    Err(e) if context.strict => return Err(e),
+                    Err(_) => {
+                        println!("❌ Editor exited with non-zero status");
+                        return Ok(true);
+                    }
                 }
             };
             
-            // Parse TOML and convert to project
-            let project_draft = ProjectDraft::from_toml(&edited_content)
-                .map_err(|e| format!("Invalid TOML: {}", e))?;
-            
-            let project = project_draft.to_project()
-                .map_err(|e| e)?;
-            
             // Save project to database
             let store = ProjectStore::new()?;
             match store.insert(&project) {
@@ -262,7 +299,7 @@ fn handle_project_command(input: &str, _context: &ReplContext) -> std::result::R
                     match &e {
                         SqliteError::SqliteFailure(sqlite_error, _) 
                             if sqlite_error.code == ErrorCode::ConstraintViolation => {
-                            println!("❌ Project with name '{}' already exists", project.name);
+                            context.report(format!("Project with name '{}' already exists", project.name))?;
                         }
                         _ => {
                             return Err(e.into());
@@ -274,6 +311,9 @@ fn handle_project_command(input: &str, _context: &ReplContext) -> std::result::R
         "help" | "--help" => {
             print_project_help();
         }
+        _ if context.strict => {
+            return Err(format!("Unknown project command: {}", argv[0]).into());
+        }
         _ => {
             println!("Unknown project command: {}", argv[0]);
             print_project_help();
@@ -291,6 +331,11 @@ fn print_repl_help(context: &ReplContext) {
     println!("  /task              - Switch to task mode");
     println!("  /use <project>     - Switch to a specific project context");
     println!("  /clear             - Clear project context (global task mode)");
+    println!("  /pomodoro <id> [focus] [break] - Focus on a task in pomodoros (default 25m/5m)");
+    println!("  /pomodoro stop | /pomodoro report - Stop, or show pomodoros per task");
+    println!("  /history [n | text | clear] - Show recent or matching input (Ctrl-R searches)");
+    println!("  /alias [name = cmd; cmd]    - List aliases, or define one for this session");
+    println!("  /alias --remove <name>      - Remove an alias for this session");
     println!();
     
     match context.mode {
@@ -315,23 +360,57 @@ fn print_task_commands() {
     println!("  add title /p high /tag work - Add with slash-style metadata");
     println!("  add --parent <id>           - Add a subtask under an existing task");
     println!("  list                        - List all tasks in hierarchical tree structure");
+    println!("  list --all                  - Include tasks scheduled to start later");
+    println!("  list /tag work              - List tasks carrying all of the given tags");
+    println!("  list /tag client /group area - Filter by parent tag and group by 'area:' tags");
+    println!("  list /status todo /p high /due <=today - Filter by status, priority and due date");
+    println!("  list --sort urgency         - Flat list, most urgent first (also '/sort urgency')");
+    println!("  next                        - Show the most urgent actionable task");
+    println!("  tui                         - Full-screen task tree with project sidebar and filter bar");
+    println!("  board [-i]                  - Kanban board by status; -i to move cards with H/L");
     println!("  update <id>                 - Update a task (opens editor)");
     println!("  update <id> /p high /tag work - Update with slash-style metadata");
-    println!("  remove <id>                 - Remove a task");
-    println!("  done <id>                   - Mark a task as done");
+    println!("  update 3,5,7-9 /p high      - Update several tasks; IDs take lists and ranges");
+    println!("  update /tag sprint12 -- /p high - Update every task matching a filter");
+    println!("  edit --bulk [--markdown] /tag x - Edit matching tasks in one file; removed entries are deleted");
+    println!("  remove <ids|filter>         - Remove tasks, e.g. remove 3,5 or remove /status done");
+    println!("  done <ids|filter>           - Mark tasks as done, e.g. done 7-9 or done /tag sprint12");
+    println!("  --dry-run / --yes           - Preview bulk changes, or skip the confirmation (bulk.confirm_over)");
+    println!("  today                       - Show overdue, due today and in-progress tasks");
+    println!("  upcoming [days]             - Show tasks due or starting in the next N days (default 7)");
+    println!("  overdue                     - Show overdue tasks");
+    println!("  calendar [month]            - Month grid of due tasks (next, prev, 2026-11, 11, nov)");
+    println!("  config [get|set|unset|path] - Show or change settings, e.g. config set display.glyphs ascii");
+    println!("  track start <id> | track stop - Start or stop the timer (one timer at a time)");
+    println!("  track log <id> 45m          - Log time already spent on a task");
+    println!("  track status                - Show the running timer");
+    println!("  timesheet [--by day|week|project] [--from <date>] [--to <date>] - Tracked time report");
+    println!("  stats [--by day|week] [--days N] [--json] - Throughput, lead time and overdue rate");
+    println!("  report burndown [--project X] [--from <date>] [--to <date>] [--svg file] - Burndown chart");
+    println!("  report flow [--project X] [--from <date>] [--to <date>] [--svg file] - Cumulative flow chart");
+    println!("  note <id> \"text\"            - Append a timestamped note to a task");
+    println!("  show <id>                   - Show a task's details and notes");
+    println!("  tags                        - List tags with task counts");
+    println!("  tag rename <old> <new>      - Rename a tag on every task");
+    println!("  tag merge <tags>... --into <tag> - Merge several tags into one");
+    println!("  view save <name> \"<filter>\" - Save a list filter (scoped to the current project)");
+    println!("  view <name>                 - Run a saved view");
+    println!("  view list | view delete <name> - List or delete saved views");
     println!();
     println!("Slash metadata options:");
     println!("  /p <priority>    - Set priority (low, medium, high, urgent)");
-    println!("  /due <date>      - Set due date (YYYY-MM-DD, MM/DD/YYYY, MM-DD)");
-    println!("  /tag <tags>      - Set tags (comma-separated)");
+    println!("  /due <date>      - Set due date (YYYY-MM-DD, MM/DD/YYYY, MM-DD, today, +3d, mon)");
+    println!("  /sched <date>    - Hide the task until this date (alias: /wait)");
+    println!("  /tag <tags>      - Set tags (comma-separated); +tag adds, -tag removes");
+    println!("                     Use 'client/acme' for hierarchies and 'area:backend' for namespaces");
     println!("  /parent <id>     - Set parent task");
-    println!("  /est <estimate>  - Set time estimate");
+    println!("  /est <estimate>  - Set time estimate (45m, 2h, 1h30m)");
 }
 
 fn print_project_commands() {
     println!("Project commands:");
     println!("  list                 - List all projects");
-    println!("  add                  - Add a new project");
+    println!("  add [name] [desc]    - Add a new project, in the editor without a name");
     println!("  update <id>          - Update a project");
     println!("  remove <id>          - Remove a project");
 }
@@ -0,0 +1,364 @@
+use crate::cli::args::Cli;
+use crate::cli::filter::SortKey;
+use crate::cli::metadata::split_slash_segments;
+use crate::config::Config;
+use crate::project::ProjectStore;
+use crate::task::TaskStore;
+use clap::{CommandFactory, ValueEnum};
+use rustyline::completion::{Completer, Pair};
+use rustyline::highlight::{CmdKind, Highlighter};
+use rustyline::hint::{Hint, Hinter};
+use rustyline::validate::Validator;
+use rustyline::{Context, Helper, Result};
+use std::borrow::Cow;
+
+const REPL_COMMANDS: [&str; 9] =
+    ["/help", "/quit", "/project", "/task", "/use", "/clear", "/pomodoro", "/history", "/alias"];
+
+const METADATA_KEYS: [&str; 6] = ["p", "due", "sched", "tag", "est", "parent"];
+const FILTER_KEYS: [&str; 6] = ["tag", "status", "p", "due", "group", "sort"];
+const PRIORITIES: [&str; 4] = ["low", "medium", "high", "urgent"];
+const STATUSES: [&str; 3] = ["todo", "in_progress", "done"];
+
+const KEY_STYLE: &str = "\x1b[36m";
+const VALUE_STYLE: &str = "\x1b[33m";
+const HINT_STYLE: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, PartialEq)]
+enum CompletionKind {
+    Command,
+    Subcommand(String),
+    ReplCommand,
+    MetadataKey,
+    FilterKey,
+    Priority,
+    Status,
+    Sort,
+    Tag,
+    TagNamespace,
+    Project,
+    TaskId,
+}
+
+// Shown after a typed task ID; never inserted into the line
+pub struct TaskHint(String);
+
+impl Hint for TaskHint {
+    fn display(&self) -> &str {
+        &self.0
+    }
+
+    fn completion(&self) -> Option<&str> {
+        None
+    }
+}
+
+// Holds one store for the session, since hints are looked up on every keypress
+pub struct ClaraHelper {
+    store: Option<TaskStore>,
+}
+
+impl ClaraHelper {
+    pub fn new() -> Self {
+        ClaraHelper { store: TaskStore::new().ok() }
+    }
+
+    fn existing_tags(&self) -> Vec<String> {
+        self.store
+            .as_ref()
+            .and_then(|store| store.tag_counts(None).ok())
+            .map(|counts| counts.into_iter().map(|(tag, _)| tag).collect())
+            .unwrap_or_default()
+    }
+
+    fn project_names(&self) -> Vec<String> {
+        ProjectStore::new()
+            .and_then(|store| store.list())
+            .map(|projects| projects.into_iter().map(|p| p.name).collect())
+            .unwrap_or_default()
+    }
+
+    fn task_titles(&self) -> Vec<(u32, String)> {
+        self.store.as_ref().and_then(|store| store.titles().ok()).unwrap_or_default()
+    }
+
+    fn candidates(&self, kind: CompletionKind, word: &str) -> Vec<Pair> {
+        let names: Vec<String> = match kind {
+            CompletionKind::TaskId => {
+                // Show titles in the list but only insert the ID
+                return self
+                    .task_titles()
+                    .into_iter()
+                    .filter(|(id, _)| id.to_string().starts_with(word))
+                    .map(|(id, title)| Pair { display: format!("{}  {}", id, title), replacement: id.to_string() })
+                    .collect();
+            }
+            CompletionKind::MetadataKey | CompletionKind::FilterKey => {
+                let keys: &[&str] = if kind == CompletionKind::FilterKey { &FILTER_KEYS } else { &METADATA_KEYS };
+                return keys
+                    .iter()
+                    .map(|key| format!("/{}", key))
+                    .filter(|key| key.starts_with(word))
+                    .map(|key| Pair { display: key.clone(), replacement: format!("{} ", key) })
+                    .collect();
+            }
+            CompletionKind::Command => subcommand_names(&[]),
+            CompletionKind::Subcommand(parent) => subcommand_names(&[parent.as_str()]),
+            CompletionKind::ReplCommand => REPL_COMMANDS.iter().map(|c| c.to_string()).collect(),
+            CompletionKind::Priority => PRIORITIES.iter().map(|p| p.to_string()).collect(),
+            CompletionKind::Status => STATUSES.iter().map(|s| s.to_string()).collect(),
+            CompletionKind::Sort => SortKey::value_variants()
+                .iter()
+                .filter_map(|key| key.to_possible_value().map(|v| v.get_name().to_string()))
+                .collect(),
+            CompletionKind::Project => self.project_names(),
+            CompletionKind::Tag => self.existing_tags(),
+            CompletionKind::TagNamespace => self
+                .existing_tags()
+                .iter()
+                .filter_map(|tag| tag.split_once([':', '/']).map(|(key, _)| key.to_string()))
+                .collect(),
+        };
+
+        let mut names = names;
+        names.sort();
+        names.dedup();
+        names
+            .into_iter()
+            .filter(|name| name.starts_with(word))
+            .map(|name| Pair { display: name.clone(), replacement: name })
+            .collect()
+    }
+}
+
+// Start of the word under the cursor, skipping a leading '+' or '-' tag operator
+fn word_start(before: &str) -> usize {
+    // Step past the separator by its own width; whitespace such as U+00A0 is multi-byte
+    let start = before
+        .rfind(|c: char| c.is_whitespace() || c == ',')
+        .map(|i| i + before[i..].chars().next().map_or(1, char::len_utf8))
+        .unwrap_or(0);
+    match before[start..].strip_prefix(['+', '-']) {
+        Some(_) if !before[start..].starts_with("--") => start + 1,
+        _ => start,
+    }
+}
+
+impl Completer for ClaraHelper {
+    type Candidate = Pair;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> Result<(usize, Vec<Pair>)> {
+        let before = &line[..pos];
+        let start = word_start(before);
+        let word = &before[start..];
+
+        match completion_kind(&before[..start], word) {
+            Some(kind) => Ok((start, self.candidates(kind, word))),
+            None => Ok((start, Vec::new())),
+        }
+    }
+}
+
+// Names of the subcommands found by following `path` from the top-level command
+fn subcommand_names(path: &[&str]) -> Vec<String> {
+    let mut command = Cli::command();
+    for name in path {
+        match command.find_subcommand(name) {
+            Some(sub) => command = sub.clone(),
+            None => return Vec::new(),
+        }
+    }
+    command
+        .get_subcommands()
+        .flat_map(|sub| std::iter::once(sub.get_name()).chain(sub.get_visible_aliases()))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+// Whether the next positional word of the command at `path` is a task ID
+fn expects_task_id(path: &[&str], positionals: usize) -> bool {
+    let mut command = Cli::command();
+    for name in path {
+        match command.find_subcommand(name) {
+            Some(sub) => command = sub.clone(),
+            None => return false,
+        }
+    }
+    command
+        .get_positionals()
+        .nth(positionals)
+        .is_some_and(|arg| matches!(arg.get_id().as_str(), "id" | "target"))
+}
+
+// Decide what kind of value is expected given the text before the word being completed
+fn completion_kind(before_word: &str, word: &str) -> Option<CompletionKind> {
+    let words: Vec<&str> = before_word.split_whitespace().collect();
+
+    if words.is_empty() {
+        return Some(if word.starts_with('/') { CompletionKind::ReplCommand } else { CompletionKind::Command });
+    }
+
+    if words[0].starts_with('/') {
+        return match words.as_slice() {
+            ["/use"] => Some(CompletionKind::Project),
+            _ => None,
+        };
+    }
+
+    // done, remove and update take a filter as their target; update's changes follow '--'
+    let is_filter = match words[0] {
+        "list" | "view" | "done" | "remove" => true,
+        "update" => words.get(1).is_some_and(|w| w.starts_with('/')) && !words.contains(&"--"),
+        _ => false,
+    };
+    if word.starts_with('/') {
+        return Some(if is_filter { CompletionKind::FilterKey } else { CompletionKind::MetadataKey });
+    }
+
+    let segments = split_slash_segments(before_word);
+    if segments.len() > 1 {
+        let (key, _) = segments.last()?.split_once(char::is_whitespace)?;
+        return match key {
+            "tag" | "tags" => Some(CompletionKind::Tag),
+            "group" => Some(CompletionKind::TagNamespace),
+            "p" | "priority" => Some(CompletionKind::Priority),
+            "status" => Some(CompletionKind::Status),
+            "sort" => Some(CompletionKind::Sort),
+            "parent" => Some(CompletionKind::TaskId),
+            _ => None,
+        };
+    }
+
+    match words.as_slice() {
+        ["tag", "rename" | "merge", ..] => return Some(CompletionKind::Tag),
+        [.., "--parent"] => return Some(CompletionKind::TaskId),
+        _ => {}
+    }
+
+    // Follow nested subcommands such as 'track start' before looking at arguments
+    let subcommands = subcommand_names(&words[..1]);
+    if words.len() == 1 && !subcommands.is_empty() {
+        return Some(CompletionKind::Subcommand(words[0].to_string()));
+    }
+    let depth = if subcommands.is_empty() { 1 } else { 2 };
+    let positionals = words[depth.min(words.len())..].iter().filter(|w| !w.starts_with('-')).count();
+    if words.len() >= depth && expects_task_id(&words[..depth], positionals) {
+        return Some(CompletionKind::TaskId);
+    }
+
+    None
+}
+
+impl Hinter for ClaraHelper {
+    type Hint = TaskHint;
+
+    fn hint(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> Option<TaskHint> {
+        if pos < line.len() {
+            return None;
+        }
+        let start = word_start(line);
+        let id: u32 = line[start..].parse().ok()?;
+        if completion_kind(&line[..start], &line[start..]) != Some(CompletionKind::TaskId) {
+            return None;
+        }
+
+        let title = self.store.as_ref()?.find_title(id).ok()??;
+        Some(TaskHint(format!("  {}", title)))
+    }
+}
+
+impl Highlighter for ClaraHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        match Config::current().display.use_color() {
+            true => highlight_metadata(line),
+            false => Cow::Borrowed(line),
+        }
+    }
+
+    fn highlight_hint<'h>(&self, hint: &'h str) -> Cow<'h, str> {
+        match Config::current().display.use_color() {
+            true => Cow::Owned(format!("{}{}{}", HINT_STYLE, hint, RESET)),
+            false => Cow::Borrowed(hint),
+        }
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize, _kind: CmdKind) -> bool {
+        true
+    }
+}
+
+// Color each '/key value' segment after the title; REPL commands are left alone
+fn highlight_metadata(line: &str) -> Cow<'_, str> {
+    let segments = split_slash_segments(line);
+    if segments.len() < 2 || segments[0].trim().is_empty() {
+        return Cow::Borrowed(line);
+    }
+
+    let mut highlighted = segments[0].to_string();
+    for segment in &segments[1..] {
+        let (key, value) = match segment.find(char::is_whitespace) {
+            Some(i) => segment.split_at(i),
+            None => (*segment, ""),
+        };
+        highlighted.push_str(&format!("{}/{}{}", KEY_STYLE, key, RESET));
+        if value.trim().is_empty() {
+            highlighted.push_str(value);
+        } else {
+            highlighted.push_str(&format!("{}{}{}", VALUE_STYLE, value, RESET));
+        }
+    }
+    Cow::Owned(highlighted)
+}
+
+impl Validator for ClaraHelper {}
+
+impl Helper for ClaraHelper {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completion_kind() {
+        assert_eq!(completion_kind("add Call /tag ", ""), Some(CompletionKind::Tag));
+        assert_eq!(completion_kind("update 3 /tag ", "+urg"), Some(CompletionKind::Tag));
+        assert_eq!(completion_kind("list /group ", ""), Some(CompletionKind::TagNamespace));
+        assert_eq!(completion_kind("tag merge a b --into ", ""), Some(CompletionKind::Tag));
+        assert_eq!(completion_kind("add Call /p ", "hi"), Some(CompletionKind::Priority));
+        assert_eq!(completion_kind("list /status ", ""), Some(CompletionKind::Status));
+        assert!(completion_kind("add client/", "").is_none());
+    }
+
+    #[test]
+    fn test_completion_kind_for_commands_and_ids() {
+        assert_eq!(completion_kind("", "do"), Some(CompletionKind::Command));
+        assert_eq!(completion_kind("", "/u"), Some(CompletionKind::ReplCommand));
+        assert_eq!(completion_kind("/use ", "wo"), Some(CompletionKind::Project));
+        assert_eq!(completion_kind("track ", "st"), Some(CompletionKind::Subcommand("track".to_string())));
+        assert_eq!(completion_kind("done ", "1"), Some(CompletionKind::TaskId));
+        assert_eq!(completion_kind("track start ", ""), Some(CompletionKind::TaskId));
+        assert_eq!(completion_kind("add --parent ", ""), Some(CompletionKind::TaskId));
+        assert_eq!(completion_kind("add Call ", "/d"), Some(CompletionKind::MetadataKey));
+        assert_eq!(completion_kind("list ", "/"), Some(CompletionKind::FilterKey));
+        assert!(completion_kind("note 3 ", "").is_none());
+    }
+
+    #[test]
+    fn test_word_start() {
+        assert_eq!(word_start("done 3"), 5);
+        assert_eq!(word_start("add x /tag a,+b"), 14);
+        assert_eq!(word_start("done\u{a0}"), "done\u{a0}".len());
+        assert_eq!(word_start("done\u{a0}12"), "done\u{a0}".len());
+    }
+
+    #[test]
+    fn test_highlight_metadata() {
+        assert_eq!(highlight_metadata("list"), "list");
+        assert_eq!(highlight_metadata("/use work"), "/use work");
+        assert_eq!(
+            highlight_metadata("add Call /p high"),
+            format!("add Call {}/p{}{} high{}", KEY_STYLE, RESET, VALUE_STYLE, RESET)
+        );
+    }
+}
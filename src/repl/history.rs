@@ -0,0 +1,73 @@
+use directories::ProjectDirs;
+use std::path::PathBuf;
+
+const DEFAULT_SHOWN: usize = 20;
+
+// History lives next to the database; with per-project history each project gets its own file
+pub fn history_path(project: Option<&str>, per_project: bool) -> PathBuf {
+    let data_dir = ProjectDirs::from("com", "you", "clara")
+        .expect("no valid home dir")
+        .data_local_dir()
+        .to_path_buf();
+
+    match project {
+        Some(name) if per_project => data_dir.join("history").join(format!("{}.txt", file_safe(name))),
+        _ => data_dir.join("history.txt"),
+    }
+}
+
+fn file_safe(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+// '/history' shows the last entries, '/history N' the last N, anything else filters by text
+pub fn select_history<'a>(entries: &[&'a str], args: &str) -> Vec<(usize, &'a str)> {
+    let numbered = entries.iter().enumerate().map(|(i, entry)| (i + 1, *entry));
+    let args = args.trim();
+
+    let (matching, limit): (Vec<(usize, &str)>, usize) = match args.parse::<usize>() {
+        Ok(limit) => (numbered.collect(), limit),
+        Err(_) if args.is_empty() => (numbered.collect(), DEFAULT_SHOWN),
+        Err(_) => {
+            let needle = args.to_lowercase();
+            (numbered.filter(|(_, entry)| entry.to_lowercase().contains(&needle)).collect(), usize::MAX)
+        }
+    };
+
+    let skip = matching.len().saturating_sub(limit);
+    matching.into_iter().skip(skip).collect()
+}
+
+pub fn print_history(entries: &[&str], args: &str) {
+    let selected = select_history(entries, args);
+    if selected.is_empty() {
+        println!("No history found.");
+        return;
+    }
+
+    let width = selected.last().map_or(1, |(n, _)| n.to_string().len());
+    for (n, entry) in selected {
+        println!("  {:>width$}  {}", n, entry, width = width);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_history() {
+        let entries = ["list", "add Call mom /p high", "done 3", "add Buy milk"];
+        assert_eq!(select_history(&entries, "2"), vec![(3, "done 3"), (4, "add Buy milk")]);
+        assert_eq!(select_history(&entries, "").len(), 4);
+        assert_eq!(select_history(&entries, "ADD"), vec![(2, "add Call mom /p high"), (4, "add Buy milk")]);
+    }
+
+    #[test]
+    fn test_per_project_history_path() {
+        assert!(history_path(Some("work"), false).ends_with("history.txt"));
+        assert!(history_path(Some("my/work"), true).ends_with("history/my_work.txt"));
+    }
+}
@@ -1,4 +1,9 @@
 pub mod runner;
 pub mod command_handler;
+pub mod alias;
+pub mod helper;
+pub mod history;
+pub mod pomodoro;
+pub mod script;
 
 pub use runner::start_repl;
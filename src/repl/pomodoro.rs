@@ -0,0 +1,328 @@
+use crate::repl::command_handler::ReplContext;
+use crate::task::duration::{format_minutes, parse_duration_minutes};
+use crate::task::TaskStore;
+use crate::timelog::TimeStore;
+use chrono::{Local, NaiveTime};
+
+const DEFAULT_FOCUS_MINUTES: u32 = 25;
+const DEFAULT_BREAK_MINUTES: u32 = 5;
+const LONG_BREAK_MINUTES: u32 = 15;
+const POMODOROS_PER_LONG_BREAK: u32 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    Focus,
+    ShortBreak,
+    LongBreak,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PomodoroEvent {
+    FocusFinished { ended_at: i64, seconds: i64 },
+    BreakFinished,
+}
+
+#[derive(Debug, Clone)]
+pub struct Pomodoro {
+    pub task_id: u32,
+    pub task_title: String,
+    pub phase: Phase,
+    pub phase_started_at: i64,
+    pub focus_seconds: i64,
+    pub break_seconds: i64,
+    pub completed: u32,
+}
+
+impl Pomodoro {
+    pub fn new(task_id: u32, task_title: String, focus_minutes: u32, break_minutes: u32, now: i64) -> Self {
+        Pomodoro {
+            task_id,
+            task_title,
+            phase: Phase::Focus,
+            phase_started_at: now,
+            focus_seconds: focus_minutes as i64 * 60,
+            break_seconds: break_minutes as i64 * 60,
+            completed: 0,
+        }
+    }
+
+    fn phase_seconds(&self) -> i64 {
+        match self.phase {
+            Phase::Focus => self.focus_seconds,
+            Phase::ShortBreak => self.break_seconds,
+            Phase::LongBreak => LONG_BREAK_MINUTES as i64 * 60,
+        }
+    }
+
+    pub fn remaining_seconds(&self, now: i64) -> i64 {
+        (self.phase_started_at + self.phase_seconds() - now).max(0)
+    }
+
+    // Move to the next phase once the current one has run out. A break starts as
+    // soon as focus ends, but the next focus only starts when the user is back,
+    // so an idle REPL never logs more than one pomodoro.
+    pub fn advance(&mut self, now: i64) -> Option<PomodoroEvent> {
+        let ends_at = self.phase_started_at + self.phase_seconds();
+        if now < ends_at {
+            return None;
+        }
+
+        match self.phase {
+            Phase::Focus => {
+                self.completed += 1;
+                self.phase = if self.completed.is_multiple_of(POMODOROS_PER_LONG_BREAK) {
+                    Phase::LongBreak
+                } else {
+                    Phase::ShortBreak
+                };
+                self.phase_started_at = ends_at;
+                Some(PomodoroEvent::FocusFinished { ended_at: ends_at, seconds: self.focus_seconds })
+            }
+            Phase::ShortBreak | Phase::LongBreak => {
+                self.phase = Phase::Focus;
+                self.phase_started_at = now;
+                Some(PomodoroEvent::BreakFinished)
+            }
+        }
+    }
+
+    pub fn prompt_prefix(&self, now: i64) -> String {
+        let remaining = self.remaining_seconds(now);
+        let icon = match self.phase {
+            Phase::Focus => "🍅",
+            Phase::ShortBreak | Phase::LongBreak => "☕",
+        };
+        format!("{} {:02}:{:02} ", icon, remaining / 60, remaining % 60)
+    }
+}
+
+pub fn handle_pomodoro_command(args: &[&str], context: &mut ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    match args {
+        [] | ["report"] => {
+            print_pomodoro_status(context);
+            print_pomodoro_report()
+        }
+        ["stop"] => {
+            match context.pomodoro.take() {
+                Some(pomodoro) => println!(
+                    "⏹️  Stopped pomodoros on task {}: '{}' ({} completed)",
+                    pomodoro.task_id, pomodoro.task_title, pomodoro.completed
+                ),
+                None => println!("No pomodoro is running."),
+            }
+            Ok(())
+        }
+        [id, durations @ ..] if durations.len() <= 2 => {
+            let id: u32 = match id.parse() {
+                Ok(id) => id,
+                Err(_) => {
+                    print_pomodoro_usage();
+                    return Ok(());
+                }
+            };
+            let (focus_minutes, break_minutes) = parse_phase_minutes(durations)?;
+            start_pomodoro(id, focus_minutes, break_minutes, context)
+        }
+        _ => {
+            print_pomodoro_usage();
+            Ok(())
+        }
+    }
+}
+
+fn start_pomodoro(
+    id: u32,
+    focus_minutes: u32,
+    break_minutes: u32,
+    context: &mut ReplContext,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let store = TaskStore::new()?;
+
+    // Check if task exists
+    let task = match store.find_by_id(id)? {
+        Some(task) => task,
+        None => {
+            println!("❌ Task with ID {} not found.", id);
+            return Ok(());
+        }
+    };
+
+    // Pomodoros log their own time, so a running timer would count it twice
+    let now = Local::now().timestamp();
+    let time_store = TimeStore::new()?;
+    if let Some(stopped) = time_store.stop(now)? {
+        println!(
+            "⏹️  Stopped the timer on task {} after {}",
+            stopped.task_id,
+            format_minutes(stopped.duration_seconds(now) / 60)
+        );
+    }
+
+    if let Some(previous) = &context.pomodoro {
+        println!("⏹️  Stopped pomodoros on task {}: '{}'", previous.task_id, previous.task_title);
+    }
+
+    println!(
+        "🍅 Focus on task {}: '{}' for {} (breaks of {}). The prompt shows the time left.",
+        task.id,
+        task.title,
+        format_minutes(focus_minutes as i64),
+        format_minutes(break_minutes as i64)
+    );
+    context.pomodoro = Some(Pomodoro::new(task.id, task.title, focus_minutes, break_minutes, now));
+
+    Ok(())
+}
+
+// Called before each prompt: finish elapsed phases and log completed pomodoros
+pub fn check_pomodoro(context: &mut ReplContext) {
+    let pomodoro = match context.pomodoro.as_mut() {
+        Some(pomodoro) => pomodoro,
+        None => return,
+    };
+
+    let now = Local::now().timestamp();
+    while let Some(event) = pomodoro.advance(now) {
+        match event {
+            PomodoroEvent::FocusFinished { ended_at, seconds } => {
+                if let Err(e) = TimeStore::new().and_then(|s| s.log(pomodoro.task_id, seconds, ended_at, "pomodoro")) {
+                    eprintln!("Error: {}", e);
+                }
+                println!(
+                    "🍅 Pomodoro #{} done on task {}: '{}'. Take a {} break.",
+                    pomodoro.completed,
+                    pomodoro.task_id,
+                    pomodoro.task_title,
+                    format_minutes(pomodoro.remaining_seconds(ended_at) / 60)
+                );
+            }
+            PomodoroEvent::BreakFinished => {
+                println!("⏰ Break over. Next pomodoro on task {} has started.", pomodoro.task_id);
+            }
+        }
+    }
+}
+
+fn print_pomodoro_status(context: &ReplContext) {
+    match &context.pomodoro {
+        Some(pomodoro) => {
+            let phase = match pomodoro.phase {
+                Phase::Focus => "focus",
+                Phase::ShortBreak => "short break",
+                Phase::LongBreak => "long break",
+            };
+            let remaining = pomodoro.remaining_seconds(Local::now().timestamp());
+            println!(
+                "🍅 Task {}: '{}' - {} with {:02}:{:02} left, {} completed this session",
+                pomodoro.task_id,
+                pomodoro.task_title,
+                phase,
+                remaining / 60,
+                remaining % 60,
+                pomodoro.completed
+            );
+        }
+        None => println!("No pomodoro is running. Start one with /pomodoro <task-id>."),
+    }
+}
+
+fn print_pomodoro_report() -> Result<(), Box<dyn std::error::Error>> {
+    let today_start = Local::now()
+        .date_naive()
+        .and_time(NaiveTime::MIN)
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|t| t.timestamp())
+        .unwrap_or(0);
+
+    let counts = TimeStore::new()?.pomodoro_counts(today_start)?;
+    if counts.is_empty() {
+        return Ok(());
+    }
+
+    let store = TaskStore::new()?;
+    println!();
+    println!("Pomodoros per task (today / total):");
+    for (task_id, today, total) in counts {
+        let title = store.find_by_id(task_id)?.map(|t| t.title).unwrap_or_else(|| "(deleted task)".to_string());
+        println!("  [{}] {:<36} {:>3} / {}", task_id, title, today, total);
+    }
+
+    Ok(())
+}
+
+// Focus and break lengths, each defaulting when left out; a phase of zero minutes
+// would never end and log empty pomodoros forever
+fn parse_phase_minutes(durations: &[&str]) -> Result<(u32, u32), String> {
+    let focus_minutes = match durations.first() {
+        Some(focus) => parse_duration_minutes(focus)?,
+        None => DEFAULT_FOCUS_MINUTES,
+    };
+    let break_minutes = match durations.get(1) {
+        Some(pause) => parse_duration_minutes(pause)?,
+        None => DEFAULT_BREAK_MINUTES,
+    };
+    if focus_minutes == 0 || break_minutes == 0 {
+        return Err("Focus and break must each last at least a minute".to_string());
+    }
+    Ok((focus_minutes, break_minutes))
+}
+
+fn print_pomodoro_usage() {
+    println!("Usage: /pomodoro <task-id> [focus] [break]   (default 25m focus, 5m break)");
+    println!("       /pomodoro stop | /pomodoro report");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pomodoro_cycles_through_phases() {
+        let mut pomodoro = Pomodoro::new(1, "Write".to_string(), 25, 5, 0);
+        assert_eq!(pomodoro.advance(60), None);
+        assert_eq!(pomodoro.remaining_seconds(60), 24 * 60);
+
+        assert_eq!(
+            pomodoro.advance(25 * 60 + 10),
+            Some(PomodoroEvent::FocusFinished { ended_at: 25 * 60, seconds: 25 * 60 })
+        );
+        assert_eq!(pomodoro.phase, Phase::ShortBreak);
+        assert_eq!(pomodoro.remaining_seconds(25 * 60 + 10), 5 * 60 - 10);
+
+        assert_eq!(pomodoro.advance(31 * 60), Some(PomodoroEvent::BreakFinished));
+        assert_eq!(pomodoro.phase, Phase::Focus);
+        assert_eq!(pomodoro.phase_started_at, 31 * 60);
+    }
+
+    #[test]
+    fn test_parse_phase_minutes() {
+        assert_eq!(parse_phase_minutes(&[]).unwrap(), (25, 5));
+        assert_eq!(parse_phase_minutes(&["50m", "10"]).unwrap(), (50, 10));
+        assert!(parse_phase_minutes(&["0", "0"]).is_err());
+        assert!(parse_phase_minutes(&["5", "0m"]).is_err());
+    }
+
+    #[test]
+    fn test_idle_pomodoro_logs_once() {
+        let mut pomodoro = Pomodoro::new(1, "Write".to_string(), 25, 5, 0);
+        let now = 3 * 60 * 60;
+        let mut events = Vec::new();
+        while let Some(event) = pomodoro.advance(now) {
+            events.push(event);
+        }
+        assert_eq!(events.len(), 2);
+        assert_eq!(pomodoro.completed, 1);
+        assert_eq!(pomodoro.phase, Phase::Focus);
+        assert_eq!(pomodoro.phase_started_at, now);
+    }
+
+    #[test]
+    fn test_every_fourth_break_is_long() {
+        let mut pomodoro = Pomodoro::new(1, "Write".to_string(), 25, 5, 0);
+        pomodoro.completed = 3;
+        pomodoro.advance(25 * 60);
+        assert_eq!(pomodoro.phase, Phase::LongBreak);
+        assert_eq!(pomodoro.remaining_seconds(25 * 60), 15 * 60);
+    }
+}
@@ -1,14 +1,35 @@
+use crate::cli::commands::agenda::agenda_counts;
 use crate::repl::command_handler::{handle_repl_command, ReplContext};
-use rustyline::{DefaultEditor, Result};
+use crate::config::Config;
+use crate::repl::helper::ClaraHelper;
+use crate::repl::history::{history_path, print_history};
+use crate::repl::pomodoro::check_pomodoro;
+use rustyline::history::DefaultHistory;
+use rustyline::{Editor, Result};
+use std::fs;
+use std::path::Path;
 
 pub fn start_repl() -> Result<()> {
-    let mut rl = DefaultEditor::new()?;
+    let config = Config::current();
+    let history_config = config.history.clone();
+    let editor_config = rustyline::Config::builder()
+        .max_history_size(history_config.size)?
+        .history_ignore_dups(history_config.ignore_dups)?
+        .build();
+
+    let mut rl: Editor<ClaraHelper, DefaultHistory> = Editor::with_config(editor_config)?;
+    rl.set_helper(Some(ClaraHelper::new()));
     let mut context = ReplContext::new();
+    context.aliases = config.aliases.clone();
+    let mut history_file = history_path(None, history_config.per_project);
+    load_history(&mut rl, &history_file);
 
     println!("🎯 Clara Task Manager REPL");
+    print_agenda_banner(&context);
     println!("Type '/help' for available commands or '/quit' to exit.");
 
     let exit_reason = loop {
+        check_pomodoro(&mut context);
         let prompt = context.get_prompt();
         let readline = rl.readline(&prompt);
         match readline {
@@ -16,6 +37,20 @@ pub fn start_repl() -> Result<()> {
                 let line = line.trim();
                 if !line.is_empty() {
                     rl.add_history_entry(line)?;
+                    save_history(&mut rl, &history_file);
+
+                    // History lives in the editor, so '/history' is answered here
+                    if let Some(args) = history_args(line) {
+                        if args.trim() == "clear" {
+                            rl.clear_history()?;
+                            let _ = fs::remove_file(&history_file);
+                            println!("🧹 History cleared.");
+                        } else {
+                            let entries: Vec<&str> = rl.history().iter().map(String::as_str).collect();
+                            print_history(&entries, args);
+                        }
+                        continue;
+                    }
 
                     match handle_repl_command(line, &mut context) {
                         Ok(should_continue) => {
@@ -27,6 +62,15 @@ pub fn start_repl() -> Result<()> {
                             eprintln!("Error: {}", e);
                         }
                     }
+
+                    // Switch history files when '/use' or '/clear' changed the project
+                    let project_name = context.current_project.as_ref().map(|p| p.name.as_str());
+                    let path = history_path(project_name, history_config.per_project);
+                    if path != history_file {
+                        rl.clear_history()?;
+                        load_history(&mut rl, &path);
+                        history_file = path;
+                    }
                 }
             }
             Err(rustyline::error::ReadlineError::Interrupted) => {
@@ -64,6 +108,48 @@ enum ExitReason {
     Error,
 }
 
+fn history_args(line: &str) -> Option<&str> {
+    match line.strip_prefix("/history") {
+        Some(args) if args.is_empty() || args.starts_with(' ') => Some(args),
+        _ => None,
+    }
+}
+
+fn load_history(rl: &mut Editor<ClaraHelper, DefaultHistory>, path: &Path) {
+    // A missing file just means no history yet
+    if path.exists()
+        && let Err(e) = rl.load_history(path)
+    {
+        eprintln!("⚠️  Could not load history from {}: {}", path.display(), e);
+    }
+}
+
+fn save_history(rl: &mut Editor<ClaraHelper, DefaultHistory>, path: &Path) {
+    let result = match path.parent() {
+        Some(dir) => fs::create_dir_all(dir).map_err(Into::into).and_then(|_| rl.append_history(path)),
+        None => rl.append_history(path),
+    };
+    if let Err(e) = result {
+        eprintln!("⚠️  Could not save history to {}: {}", path.display(), e);
+    }
+}
+
+fn print_agenda_banner(context: &ReplContext) {
+    let project_id = context.current_project.as_ref().map(|p| p.id);
+
+    // The banner is informational only; a failing query should not block the REPL
+    if let Ok((overdue, today)) = agenda_counts(project_id) {
+        match (overdue, today) {
+            (0, 0) => {}
+            (0, today) => println!("📅 {} due today. Type 'today' for your agenda.", today),
+            (overdue, today) => println!(
+                "⚠️  {} overdue, 📅 {} due today. Type 'today' for your agenda.",
+                overdue, today
+            ),
+        }
+    }
+}
+
 fn say_goodbye() {
     println!("Goodbye! 👋");
 }
@@ -0,0 +1,87 @@
+use crate::config::Config;
+use crate::db;
+use crate::repl::command_handler::{handle_repl_command, ReplContext};
+use crate::task::TaskStore;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+pub struct ScriptOptions {
+    pub stop_on_error: bool,
+    pub transactional: bool,
+}
+
+// Run each line as if typed at the REPL; '-' reads the script from stdin
+pub fn run_script(source: &Path, options: &ScriptOptions) -> Result<(), Box<dyn std::error::Error>> {
+    let content = if source == Path::new("-") {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content)?;
+        content
+    } else {
+        fs::read_to_string(source).map_err(|e| format!("Cannot read script {}: {}", source.display(), e))?
+    };
+
+    let mut context = ReplContext::new();
+    context.aliases = Config::current().aliases.clone();
+    context.strict = true;
+
+    // Every store then uses one connection, so a failure rolls back the whole script
+    if options.transactional {
+        db::begin_shared(&TaskStore::get_db_path())?;
+    }
+
+    let mut failures = 0;
+    let mut executed = 0;
+    for (line_number, line) in script_lines(&content) {
+        println!("{}{}", context.get_prompt(), line);
+        executed += 1;
+
+        match handle_repl_command(line, &mut context) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => {
+                eprintln!("❌ Line {}: {}", line_number, e);
+                failures += 1;
+                if options.stop_on_error || options.transactional {
+                    break;
+                }
+            }
+        }
+    }
+
+    if options.transactional {
+        db::finish_shared(failures == 0)?;
+        if failures > 0 {
+            println!("↩️  Rolled back all changes made by the script.");
+        }
+    }
+
+    match failures {
+        0 => {
+            println!("✅ Script finished: {} command(s) run.", executed);
+            Ok(())
+        }
+        failures => Err(format!("Script had {} failing command(s) out of {} run", failures, executed).into()),
+    }
+}
+
+// Non-empty lines with their 1-based line numbers; '#' starts a comment line
+fn script_lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_lines_skip_blanks_and_comments() {
+        let script = "# seed project\n/use work\n\n  add Plan /p high  \n# done\nlist\n";
+        let lines: Vec<(usize, &str)> = script_lines(script).collect();
+        assert_eq!(lines, vec![(2, "/use work"), (4, "add Plan /p high"), (6, "list")]);
+    }
+}
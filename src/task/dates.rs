@@ -0,0 +1,89 @@
+use chrono::{Datelike, Duration, Local, NaiveDate, TimeZone, Weekday};
+
+pub fn parse_due_date(date_str: &str) -> Result<i64, String> {
+    end_of_day_timestamp(parse_date(date_str)?)
+}
+
+pub fn start_of_day_timestamp(date: NaiveDate) -> Result<i64, String> {
+    let datetime = date.and_hms_opt(0, 0, 0)
+        .ok_or("Invalid date")?;
+    Ok(Local.from_local_datetime(&datetime).earliest()
+        .ok_or("Invalid date conversion")?.timestamp())
+}
+
+pub fn end_of_day_timestamp(date: NaiveDate) -> Result<i64, String> {
+    let datetime = date.and_hms_opt(23, 59, 59)
+        .ok_or("Invalid date")?;
+    Ok(Local.from_local_datetime(&datetime).single()
+        .ok_or("Invalid date conversion")?.timestamp())
+}
+
+pub fn parse_date(date_str: &str) -> Result<NaiveDate, String> {
+    let date_str = date_str.trim();
+    let today = Local::now().date_naive();
+
+    // Relative keywords: today, tomorrow, yesterday, +3d
+    match date_str.to_lowercase().as_str() {
+        "today" => return Ok(today),
+        "tomorrow" => return Ok(today + Duration::days(1)),
+        "yesterday" => return Ok(today - Duration::days(1)),
+        _ => {}
+    }
+    if let Some(days) = date_str.strip_prefix('+').and_then(|d| d.strip_suffix('d'))
+        && let Ok(days) = days.parse::<i64>() {
+        return Ok(today + Duration::days(days));
+    }
+
+    // Weekday names refer to the next such day after today: mon, friday
+    if let Ok(weekday) = date_str.parse::<Weekday>() {
+        let ahead = (weekday.num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64 - 1)
+            .rem_euclid(7) + 1;
+        return Ok(today + Duration::days(ahead));
+    }
+
+    // Try parsing various date formats
+    
+    // ISO format: 2025-08-10
+    if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
+        return Ok(date);
+    }
+    
+    // US format: 08/10/2025
+    if let Ok(date) = NaiveDate::parse_from_str(date_str, "%m/%d/%Y") {
+        return Ok(date);
+    }
+    
+    // Short format: 08-10 (current year)
+    let current_year = Local::now().year();
+    let with_year = format!("{}-{}", current_year, date_str);
+    if let Ok(date) = NaiveDate::parse_from_str(&with_year, "%Y-%m-%d") {
+        return Ok(date);
+    }
+    
+    Err(format!("Invalid date format: {}. Use YYYY-MM-DD, MM/DD/YYYY, MM-DD, today, tomorrow, +Nd or a weekday", date_str))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_relative_dates() {
+        let today = Local::now().date_naive();
+        assert_eq!(parse_date("today").unwrap(), today);
+        assert_eq!(parse_date("Tomorrow").unwrap(), today + Duration::days(1));
+        assert_eq!(parse_date("+3d").unwrap(), today + Duration::days(3));
+        assert!(parse_date("someday").is_err());
+    }
+
+    #[test]
+    fn test_parse_weekday_is_strictly_ahead() {
+        let today = Local::now().date_naive();
+        for name in ["mon", "tue", "wed", "thu", "fri", "sat", "sun"] {
+            let date = parse_date(name).unwrap();
+            let ahead = (date - today).num_days();
+            assert!((1..=7).contains(&ahead), "{} is {} days ahead", name, ahead);
+            assert_eq!(date.weekday(), name.parse::<Weekday>().unwrap());
+        }
+    }
+}
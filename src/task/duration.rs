@@ -0,0 +1,61 @@
+// Durations such as '45m', '2h', '1h30m', '1.5h' or bare minutes ('90')
+pub fn parse_duration_minutes(value: &str) -> Result<u32, String> {
+    let value = value.trim().to_lowercase();
+    let invalid = || format!("Invalid duration: {}. Use e.g. 45m, 2h, 1h30m", value);
+
+    if let Ok(minutes) = value.parse::<u32>() {
+        return if minutes > 0 { Ok(minutes) } else { Err(invalid()) };
+    }
+
+    let mut total = 0.0;
+    let mut number = String::new();
+    for c in value.chars() {
+        match c {
+            '0'..='9' | '.' => number.push(c),
+            'h' | 'm' => {
+                let amount: f64 = number.parse().map_err(|_| invalid())?;
+                total += if c == 'h' { amount * 60.0 } else { amount };
+                number.clear();
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    if !number.is_empty() || total <= 0.0 {
+        return Err(invalid());
+    }
+    Ok(total.round() as u32)
+}
+
+pub fn format_minutes(minutes: i64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h{}m", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_minutes() {
+        assert_eq!(parse_duration_minutes("45m").unwrap(), 45);
+        assert_eq!(parse_duration_minutes("2h").unwrap(), 120);
+        assert_eq!(parse_duration_minutes("1h30m").unwrap(), 90);
+        assert_eq!(parse_duration_minutes("1.5h").unwrap(), 90);
+        assert_eq!(parse_duration_minutes("25").unwrap(), 25);
+        assert!(parse_duration_minutes("soon").is_err());
+        assert!(parse_duration_minutes("2x").is_err());
+        assert!(parse_duration_minutes("0m").is_err());
+        assert!(parse_duration_minutes("0").is_err());
+    }
+
+    #[test]
+    fn test_format_minutes() {
+        assert_eq!(format_minutes(45), "45m");
+        assert_eq!(format_minutes(120), "2h");
+        assert_eq!(format_minutes(95), "1h35m");
+    }
+}
@@ -1,5 +1,10 @@
+pub mod dates;
+pub mod duration;
 pub mod model;
 pub mod store;
+pub mod tags;
+pub mod template;
+pub mod urgency;
 
 pub use model::{Status, Task, TaskDraft};
-pub use store::TaskStore;
\ No newline at end of file
+pub use store::TaskStore;
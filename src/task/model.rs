@@ -1,3 +1,8 @@
+use crate::config::DefaultsConfig;
+use crate::project::Project;
+use crate::task::duration::{format_minutes, parse_duration_minutes};
+use crate::task::template::{template_header, TaskFields};
+use crate::task::tags::normalize_tags;
 use chrono::{DateTime, Local, TimeZone};
 use rusqlite::types::{FromSql, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
 use serde::{Deserialize, Serialize};
@@ -91,10 +96,13 @@ impl FromSql for Priority {
 pub struct Task {
     pub id: u32,
     pub title: String,
+    pub notes: Option<String>,
     pub status: Status,
     pub tags: Vec<String>,
     pub priority: Priority,
     pub due_date: Option<i64>,
+    pub scheduled_date: Option<i64>,
+    pub estimate_minutes: Option<u32>,
     pub created_at: i64,
     pub updated_at: i64,
     pub completed_at: Option<i64>,
@@ -138,8 +146,12 @@ pub struct TaskDraft {
     pub tags: Vec<String>,
     pub priority: String,
     pub due_date: Option<i64>,
+    pub scheduled_date: Option<i64>,
+    pub estimate: Option<String>,
     pub parent_id: Option<u32>,
     pub project_id: Option<u32>,
+    #[serde(default)]
+    pub notes: String,
     pub extras: Option<Value>,
 }
 
@@ -151,13 +163,25 @@ impl TaskDraft {
             tags: Vec::new(),
             priority: String::from("medium"),
             due_date: None,
+            scheduled_date: None,
+            estimate: None,
             parent_id: None,
             project_id: None,
+            notes: String::new(),
             extras: None,
         }
     }
 
-    pub fn to_task(self) -> Result<Task, String> {
+    // A new draft with the configured default status and priority
+    pub fn from_defaults(defaults: &DefaultsConfig) -> Self {
+        TaskDraft {
+            status: defaults.status.clone(),
+            priority: defaults.priority.clone(),
+            ..TaskDraft::new()
+        }
+    }
+
+    pub fn into_task(self) -> Result<Task, String> {
         if self.title.trim().is_empty() {
             return Err("Task title cannot be empty".to_string());
         }
@@ -171,30 +195,42 @@ impl TaskDraft {
 
         let priority = Priority::from_string(&self.priority);
 
+        let estimate_minutes = match self.estimate.as_deref().map(str::trim) {
+            Some(estimate) if !estimate.is_empty() => Some(parse_duration_minutes(estimate)?),
+            _ => None,
+        };
+
         let now = Local::now().timestamp();
+        let completed_at = matches!(status, Status::Done).then_some(now);
 
         Ok(Task {
             id: 0, // Will be auto-generated by DB
             title: self.title.trim().to_string(),
+            notes: Some(self.notes.trim_end().to_string()).filter(|n| !n.trim().is_empty()),
             status,
-            tags: self.tags,
+            tags: normalize_tags(&self.tags),
             priority,
             due_date: self.due_date,
+            scheduled_date: self.scheduled_date,
+            estimate_minutes,
             created_at: now,
             updated_at: now,
-            completed_at: None,
+            completed_at,
             parent_id: self.parent_id,
             project_id: self.project_id,
             extras: self.extras,
         })
     }
 
-    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
-        toml::to_string_pretty(self)
+    // An editable template: commented header, readable dates and the project by name
+    pub fn to_toml(&self, projects: &[Project]) -> Result<String, toml::ser::Error> {
+        let fields = toml::to_string_pretty(&TaskFields::from_draft(self, projects))?;
+        Ok(template_header(projects) + &fields)
     }
 
-    pub fn from_toml(toml: &str) -> Result<Self, toml::de::Error> {
-        toml::from_str(toml)
+    pub fn from_toml(toml: &str, projects: &[Project]) -> Result<Self, String> {
+        let fields: TaskFields = toml::from_str(toml).map_err(|e| format!("Invalid TOML: {}", e))?;
+        fields.into_draft(projects)
     }
 }
 
@@ -215,6 +251,25 @@ impl Task {
         self.completed_at.map(|ts| Local.timestamp_opt(ts, 0).unwrap())
     }
 
+    pub fn scheduled_date_datetime(&self) -> Option<DateTime<Local>> {
+        self.scheduled_date.map(|ts| Local.timestamp_opt(ts, 0).unwrap())
+    }
+
+    // Tasks scheduled for a later date stay hidden from default views until then
+    pub fn is_waiting(&self, now: i64) -> bool {
+        self.scheduled_date.is_some_and(|scheduled| scheduled > now)
+    }
+
+    pub fn append_note(&mut self, text: &str) {
+        let heading = format!("### {}", Local::now().format("%Y-%m-%d %H:%M"));
+        let entry = format!("{}\n{}", heading, text.trim());
+
+        self.notes = Some(match self.notes.take() {
+            Some(existing) => format!("{}\n\n{}", existing.trim_end(), entry),
+            None => entry,
+        });
+    }
+
     pub fn to_draft(&self) -> TaskDraft {
         TaskDraft {
             title: self.title.clone(),
@@ -222,8 +277,11 @@ impl Task {
             tags: self.tags.clone(),
             priority: self.priority.to_string(),
             due_date: self.due_date,
+            scheduled_date: self.scheduled_date,
+            estimate: self.estimate_minutes.map(|m| format_minutes(m as i64)),
             parent_id: self.parent_id,
             project_id: self.project_id,
+            notes: self.notes.clone().unwrap_or_default(),
             extras: self.extras.clone(),
         }
     }
@@ -1,11 +1,17 @@
-use crate::task::model::{Status, Task};
-use chrono::Local;
+use crate::db;
+use crate::task::model::{Priority, Status, Task};
+use crate::task::tags::normalize_tags;
+use crate::timelog::TimeStore;
+use chrono::{Local, Weekday};
 use directories::ProjectDirs;
-use rusqlite::{Connection, Result};
+use rusqlite::{Connection, OptionalExtension, Result};
 use std::path::PathBuf;
+use std::rc::Rc;
+
+const TASK_COLUMNS: &str = "id, title, notes, created_at, status, tags, priority, due_date, scheduled_date, estimate_minutes, updated_at, completed_at, parent_id, project_id, extras";
 
 pub struct TaskStore {
-    conn: Connection,
+    conn: Rc<Connection>,
 }
 
 impl TaskStore {
@@ -18,11 +24,14 @@ impl TaskStore {
         Ok(Task {
             id: row.get("id")?,
             title: row.get("title")?,
+            notes: row.get("notes")?,
             created_at: row.get("created_at")?,
             status: row.get("status")?,
             tags,
             priority: row.get("priority")?,
             due_date: row.get("due_date")?,
+            scheduled_date: row.get("scheduled_date")?,
+            estimate_minutes: row.get("estimate_minutes")?,
             updated_at: row.get("updated_at")?,
             completed_at: row.get("completed_at")?,
             parent_id: row.get("parent_id")?,
@@ -33,14 +42,14 @@ impl TaskStore {
 
     pub fn new() -> Result<Self> {
         let db_path = Self::get_db_path();
-        let conn = Connection::open(db_path)?;
+        let conn = db::connect(&db_path)?;
 
         let store = TaskStore { conn };
         store.init_tables()?;
         Ok(store)
     }
 
-    fn get_db_path() -> PathBuf {
+    pub fn get_db_path() -> PathBuf {
         ProjectDirs::from("com", "you", "clara")
             .expect("no valid home dir")
             .data_local_dir()
@@ -52,11 +61,14 @@ impl TaskStore {
             "CREATE TABLE IF NOT EXISTS tasks (
                 id           INTEGER PRIMARY KEY,
                 title        TEXT NOT NULL,
+                notes        TEXT,
                 created_at   INTEGER NOT NULL,
                 status       TEXT NOT NULL,
                 tags         TEXT NOT NULL DEFAULT '[]',
                 priority     TEXT NOT NULL DEFAULT 'medium',
                 due_date     INTEGER,
+                scheduled_date INTEGER,
+                estimate_minutes INTEGER,
                 updated_at   INTEGER NOT NULL,
                 completed_at INTEGER,
                 parent_id    INTEGER,
@@ -67,10 +79,114 @@ impl TaskStore {
             )",
             [],
         )?;
+        self.ensure_column("notes", "TEXT")?;
+        self.ensure_column("scheduled_date", "INTEGER")?;
+        self.ensure_column("estimate_minutes", "INTEGER")?;
+        self.init_history_table()?;
+        self.init_tag_table()?;
         Ok(())
     }
 
-    pub fn insert(&self, task: &Task) -> Result<()> {
+    fn init_tag_table(&self) -> Result<()> {
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'task_tags')",
+            [],
+            |row| row.get(0),
+        )?;
+
+        // Created together with its backfill, so a failed backfill is retried next time
+        db::atomically(&self.conn, || {
+            self.conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS task_tags (
+                    task_id      INTEGER NOT NULL,
+                    tag          TEXT NOT NULL,
+                    PRIMARY KEY(task_id, tag),
+                    FOREIGN KEY(task_id) REFERENCES tasks(id)
+                );
+                CREATE INDEX IF NOT EXISTS idx_task_tags_tag ON task_tags(tag);",
+            )?;
+
+            // Backfill the join table from the JSON column the first time it is created
+            if !exists {
+                for task in self.list()? {
+                    let tags = normalize_tags(&task.tags);
+                    if tags != task.tags {
+                        let tags_json = serde_json::to_string(&tags)
+                            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(e.into()))?;
+                        self.conn.execute("UPDATE tasks SET tags = ?1 WHERE id = ?2", rusqlite::params![tags_json, task.id])?;
+                    }
+                    self.sync_tags(task.id, &tags)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    fn init_history_table(&self) -> Result<()> {
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'task_status_history')",
+            [],
+            |row| row.get(0),
+        )?;
+
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS task_status_history (
+                id           INTEGER PRIMARY KEY,
+                task_id      INTEGER NOT NULL,
+                status       TEXT NOT NULL,
+                changed_at   INTEGER NOT NULL,
+                FOREIGN KEY(task_id) REFERENCES tasks(id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_task_status_history_task ON task_status_history(task_id);",
+        )?;
+
+        // Approximate the history of existing tasks: created as todo, then moved
+        // to their current status when they were completed or last updated
+        if !exists {
+            self.conn.execute_batch(
+                "INSERT INTO task_status_history (task_id, status, changed_at)
+                    SELECT id, 'todo', created_at FROM tasks;
+                 INSERT INTO task_status_history (task_id, status, changed_at)
+                    SELECT id, status, COALESCE(completed_at, updated_at) FROM tasks WHERE status != 'todo';",
+            )?;
+        }
+        Ok(())
+    }
+
+    // Append a history entry when the stored status differs; call before writing the new status
+    fn record_status_change(&self, task_id: u32, status: &Status, changed_at: i64) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO task_status_history (task_id, status, changed_at)
+             SELECT id, ?2, ?3 FROM tasks WHERE id = ?1 AND status != ?2",
+            rusqlite::params![task_id, status, changed_at],
+        )?;
+        Ok(())
+    }
+
+    fn sync_tags(&self, task_id: u32, tags: &[String]) -> Result<()> {
+        self.conn.execute("DELETE FROM task_tags WHERE task_id = ?1", [task_id])?;
+        for tag in tags {
+            self.conn.execute(
+                "INSERT OR IGNORE INTO task_tags (task_id, tag) VALUES (?1, ?2)",
+                rusqlite::params![task_id, tag],
+            )?;
+        }
+        Ok(())
+    }
+
+    // Add columns introduced after the initial schema to existing databases
+    fn ensure_column(&self, name: &str, definition: &str) -> Result<()> {
+        let mut stmt = self.conn.prepare("SELECT name FROM pragma_table_info('tasks')")?;
+        let columns = stmt.query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>>>()?;
+
+        if !columns.iter().any(|c| c == name) {
+            self.conn.execute(&format!("ALTER TABLE tasks ADD COLUMN {} {}", name, definition), [])?;
+        }
+        Ok(())
+    }
+
+    pub fn insert(&self, task: &Task) -> Result<u32> {
         let tags_json = serde_json::to_string(&task.tags).unwrap_or_else(|_| "[]".to_string());
         let extras_json = task
             .extras
@@ -78,15 +194,18 @@ impl TaskStore {
             .map(|e| serde_json::to_string(e).unwrap_or_else(|_| "null".to_string()));
 
         self.conn.execute(
-            "INSERT INTO tasks (title, created_at, status, tags, priority, due_date, updated_at, completed_at, parent_id, project_id, extras)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            "INSERT INTO tasks (title, notes, created_at, status, tags, priority, due_date, scheduled_date, estimate_minutes, updated_at, completed_at, parent_id, project_id, extras)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
             rusqlite::params![
                 task.title,
+                task.notes,
                 task.created_at,
                 task.status,
                 tags_json,
                 task.priority,
                 task.due_date,
+                task.scheduled_date,
+                task.estimate_minutes,
                 task.updated_at,
                 task.completed_at,
                 task.parent_id,
@@ -94,13 +213,20 @@ impl TaskStore {
                 extras_json
             ],
         )?;
-        Ok(())
+
+        let id = self.conn.last_insert_rowid() as u32;
+        self.sync_tags(id, &task.tags)?;
+        self.conn.execute(
+            "INSERT INTO task_status_history (task_id, status, changed_at) VALUES (?1, ?2, ?3)",
+            rusqlite::params![id, task.status, task.created_at],
+        )?;
+        Ok(id)
     }
 
     pub fn list(&self) -> Result<Vec<Task>> {
         let mut stmt = self
             .conn
-            .prepare("SELECT id, title, created_at, status, tags, priority, due_date, updated_at, completed_at, parent_id, project_id, extras FROM tasks")?;
+            .prepare(&format!("SELECT {} FROM tasks", TASK_COLUMNS))?;
         let task_iter = stmt.query_map([], Self::map_row_to_task)?;
 
         let mut tasks = Vec::new();
@@ -112,21 +238,47 @@ impl TaskStore {
 
     pub fn update_status(&self, id: u32, status: Status) -> Result<()> {
         let now = Local::now().timestamp();
+        self.record_status_change(id, &status, now)?;
+        // Keep the first completion time when a done task is marked done again
         self.conn.execute(
-            "UPDATE tasks SET status = ?1, updated_at = ?2 WHERE id = ?3",
+            "UPDATE tasks SET status = ?1, updated_at = ?2,
+                completed_at = CASE WHEN ?1 = 'done' THEN COALESCE(completed_at, ?2) ELSE NULL END
+             WHERE id = ?3",
             rusqlite::params![status, now, id],
         )?;
         Ok(())
     }
 
     pub fn delete(&self, id: u32) -> Result<()> {
+        // Tracked time goes with the task
+        let time_store = TimeStore::with_connection(self.conn.clone())?;
+        db::atomically(&self.conn, || {
+            self.conn
+                .execute("DELETE FROM task_tags WHERE task_id = ?1", rusqlite::params![id])?;
+            self.conn
+                .execute("DELETE FROM task_status_history WHERE task_id = ?1", rusqlite::params![id])?;
+            time_store.delete_for_task(id)?;
+            self.conn
+                .execute("DELETE FROM tasks WHERE id = ?1", rusqlite::params![id])?;
+            Ok(())
+        })
+    }
+
+    // Just the IDs and titles, for completion and hints in the REPL
+    pub fn titles(&self) -> Result<Vec<(u32, String)>> {
+        let mut stmt = self.conn.prepare("SELECT id, title FROM tasks ORDER BY id")?;
+        let title_iter = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        title_iter.collect()
+    }
+
+    pub fn find_title(&self, id: u32) -> Result<Option<String>> {
         self.conn
-            .execute("DELETE FROM tasks WHERE id = ?1", rusqlite::params![id])?;
-        Ok(())
+            .query_row("SELECT title FROM tasks WHERE id = ?1", [id], |row| row.get(0))
+            .optional()
     }
 
     pub fn find_by_id(&self, id: u32) -> Result<Option<Task>> {
-        let mut stmt = self.conn.prepare("SELECT id, title, created_at, status, tags, priority, due_date, updated_at, completed_at, parent_id, project_id, extras FROM tasks WHERE id = ?1")?;
+        let mut stmt = self.conn.prepare(&format!("SELECT {} FROM tasks WHERE id = ?1", TASK_COLUMNS))?;
         let mut task_iter = stmt.query_map([id], Self::map_row_to_task)?;
 
         match task_iter.next() {
@@ -139,18 +291,22 @@ impl TaskStore {
         let tags_json = serde_json::to_string(&task.tags)
             .map_err(|e| rusqlite::Error::ToSqlConversionFailure(e.into()))?;
         let extras_json = task.extras.as_ref()
-            .map(|e| serde_json::to_string(e))
+            .map(serde_json::to_string)
             .transpose()
             .map_err(|e| rusqlite::Error::ToSqlConversionFailure(e.into()))?;
-        
+
+        self.record_status_change(task.id, &task.status, task.updated_at)?;
         self.conn.execute(
-            "UPDATE tasks SET title = ?1, status = ?2, tags = ?3, priority = ?4, due_date = ?5, updated_at = ?6, completed_at = ?7, parent_id = ?8, project_id = ?9, extras = ?10 WHERE id = ?11",
+            "UPDATE tasks SET title = ?1, notes = ?2, status = ?3, tags = ?4, priority = ?5, due_date = ?6, scheduled_date = ?7, estimate_minutes = ?8, updated_at = ?9, completed_at = ?10, parent_id = ?11, project_id = ?12, extras = ?13 WHERE id = ?14",
             rusqlite::params![
                 task.title,
+                task.notes,
                 task.status,
                 tags_json,
                 task.priority,
                 task.due_date,
+                task.scheduled_date,
+                task.estimate_minutes,
                 task.updated_at,
                 task.completed_at,
                 task.parent_id,
@@ -159,13 +315,14 @@ impl TaskStore {
                 task.id
             ],
         )?;
+        self.sync_tags(task.id, &task.tags)?;
         Ok(())
     }
 
     pub fn find_children(&self, parent_id: u32) -> Result<Vec<Task>> {
         let mut stmt = self
             .conn
-            .prepare("SELECT id, title, created_at, status, tags, priority, due_date, updated_at, completed_at, parent_id, project_id, extras FROM tasks WHERE parent_id = ?1")?;
+            .prepare(&format!("SELECT {} FROM tasks WHERE parent_id = ?1", TASK_COLUMNS))?;
         let task_iter = stmt.query_map([parent_id], Self::map_row_to_task)?;
 
         let mut tasks = Vec::new();
@@ -178,7 +335,7 @@ impl TaskStore {
     pub fn find_root_tasks(&self) -> Result<Vec<Task>> {
         let mut stmt = self
             .conn
-            .prepare("SELECT id, title, created_at, status, tags, priority, due_date, updated_at, completed_at, parent_id, project_id, extras FROM tasks WHERE parent_id IS NULL")?;
+            .prepare(&format!("SELECT {} FROM tasks WHERE parent_id IS NULL", TASK_COLUMNS))?;
         let task_iter = stmt.query_map([], Self::map_row_to_task)?;
 
         let mut tasks = Vec::new();
@@ -191,7 +348,7 @@ impl TaskStore {
     pub fn find_tasks_by_project(&self, project_id: u32) -> Result<Vec<Task>> {
         let mut stmt = self
             .conn
-            .prepare("SELECT id, title, created_at, status, tags, priority, due_date, updated_at, completed_at, parent_id, project_id, extras FROM tasks WHERE project_id = ?1")?;
+            .prepare(&format!("SELECT {} FROM tasks WHERE project_id = ?1", TASK_COLUMNS))?;
         let task_iter = stmt.query_map([project_id], Self::map_row_to_task)?;
 
         let mut tasks = Vec::new();
@@ -204,7 +361,7 @@ impl TaskStore {
     pub fn find_root_tasks_by_project(&self, project_id: u32) -> Result<Vec<Task>> {
         let mut stmt = self
             .conn
-            .prepare("SELECT id, title, created_at, status, tags, priority, due_date, updated_at, completed_at, parent_id, project_id, extras FROM tasks WHERE project_id = ?1 AND parent_id IS NULL")?;
+            .prepare(&format!("SELECT {} FROM tasks WHERE project_id = ?1 AND parent_id IS NULL", TASK_COLUMNS))?;
         let task_iter = stmt.query_map([project_id], Self::map_row_to_task)?;
 
         let mut tasks = Vec::new();
@@ -213,4 +370,248 @@ impl TaskStore {
         }
         Ok(tasks)
     }
+
+    pub fn find_by_tags(&self, tags: &[String], project_id: Option<u32>) -> Result<Vec<Task>> {
+        // Every requested tag must match, either exactly or as the parent of a
+        // hierarchical ('client/acme') or namespaced ('area:backend') tag.
+        // Prefix matches are expressed as ranges so they can use the tag index.
+        let conditions = (1..=tags.len())
+            .map(|i| {
+                format!(
+                    "id IN (SELECT task_id FROM task_tags WHERE tag = ?{i}
+                        OR (tag >= ?{i} || '/' AND tag < ?{i} || '0')
+                        OR (tag >= ?{i} || ':' AND tag < ?{i} || ';'))"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let mut sql = format!("SELECT {} FROM tasks WHERE {}", TASK_COLUMNS, conditions);
+        let mut params: Vec<&dyn rusqlite::ToSql> = tags.iter().map(|t| t as &dyn rusqlite::ToSql).collect();
+        if let Some(project_id) = &project_id {
+            sql.push_str(&format!(" AND project_id = ?{}", tags.len() + 1));
+            params.push(project_id);
+        }
+        sql.push_str(" ORDER BY id");
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let task_iter = stmt.query_map(params.as_slice(), Self::map_row_to_task)?;
+
+        let mut tasks = Vec::new();
+        for task in task_iter {
+            tasks.push(task?);
+        }
+        Ok(tasks)
+    }
+
+    pub fn tag_counts(&self, project_id: Option<u32>) -> Result<Vec<(String, u32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT tt.tag, COUNT(*) AS count FROM task_tags tt
+             JOIN tasks t ON t.id = tt.task_id
+             WHERE ?1 IS NULL OR t.project_id = ?1
+             GROUP BY tt.tag ORDER BY count DESC, tt.tag",
+        )?;
+        let tag_iter = stmt.query_map([project_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+        let mut counts = Vec::new();
+        for count in tag_iter {
+            counts.push(count?);
+        }
+        Ok(counts)
+    }
+
+    // Every status change as (task_id, status, changed_at), oldest first per task
+    pub fn status_history(&self, project_id: Option<u32>) -> Result<Vec<(u32, Status, i64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT h.task_id, h.status, h.changed_at FROM task_status_history h
+             JOIN tasks t ON t.id = h.task_id
+             WHERE ?1 IS NULL OR t.project_id = ?1
+             ORDER BY h.task_id, h.changed_at, h.id",
+        )?;
+        let change_iter = stmt.query_map([project_id], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+
+        let mut changes = Vec::new();
+        for change in change_iter {
+            changes.push(change?);
+        }
+        Ok(changes)
+    }
+
+    // Tasks created per local day (or per week starting on the given day) since the given moment
+    pub fn created_per_period(&self, week_start: Option<Weekday>, since: i64, project_id: Option<u32>) -> Result<Vec<(String, u32)>> {
+        self.count_per_period("created_at", week_start, since, project_id)
+    }
+
+    pub fn completed_per_period(&self, week_start: Option<Weekday>, since: i64, project_id: Option<u32>) -> Result<Vec<(String, u32)>> {
+        self.count_per_period("completed_at", week_start, since, project_id)
+    }
+
+    fn count_per_period(&self, column: &str, week_start: Option<Weekday>, since: i64, project_id: Option<u32>) -> Result<Vec<(String, u32)>> {
+        // 'weekday N' moves forward to the last day of the week, then six days back is its first day
+        let modifiers = match week_start {
+            Some(first) => format!(", 'weekday {}', '-6 days'", first.pred().num_days_from_sunday()),
+            None => String::new(),
+        };
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT date({column}, 'unixepoch', 'localtime'{modifiers}) AS period, COUNT(*) FROM tasks
+             WHERE {column} >= ?1 AND (?2 IS NULL OR project_id = ?2)
+             GROUP BY period ORDER BY period"
+        ))?;
+        let count_iter = stmt.query_map(rusqlite::params![since, project_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+        let mut counts = Vec::new();
+        for count in count_iter {
+            counts.push(count?);
+        }
+        Ok(counts)
+    }
+
+    pub fn status_counts(&self, project_id: Option<u32>) -> Result<Vec<(Status, u32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT status, COUNT(*) FROM tasks WHERE ?1 IS NULL OR project_id = ?1 GROUP BY status",
+        )?;
+        let count_iter = stmt.query_map([project_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+        let mut counts = Vec::new();
+        for count in count_iter {
+            counts.push(count?);
+        }
+        Ok(counts)
+    }
+
+    pub fn priority_counts(&self, project_id: Option<u32>) -> Result<Vec<(Priority, u32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT priority, COUNT(*) FROM tasks WHERE ?1 IS NULL OR project_id = ?1 GROUP BY priority",
+        )?;
+        let count_iter = stmt.query_map([project_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+        let mut counts = Vec::new();
+        for count in count_iter {
+            counts.push(count?);
+        }
+        Ok(counts)
+    }
+
+    pub fn project_counts(&self) -> Result<Vec<(Option<u32>, u32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT project_id, COUNT(*) AS count FROM tasks GROUP BY project_id ORDER BY count DESC",
+        )?;
+        let count_iter = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+        let mut counts = Vec::new();
+        for count in count_iter {
+            counts.push(count?);
+        }
+        Ok(counts)
+    }
+
+    // (average seconds from creation to completion, number of completed tasks measured)
+    pub fn lead_time(&self, project_id: Option<u32>) -> Result<(Option<f64>, u32)> {
+        self.conn.query_row(
+            "SELECT AVG(completed_at - created_at), COUNT(*) FROM tasks
+             WHERE status = 'done' AND completed_at IS NOT NULL AND (?1 IS NULL OR project_id = ?1)",
+            [project_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+    }
+
+    // Among tasks whose due date has passed or that are done: (total, late, still open and overdue)
+    pub fn due_counts(&self, now: i64, project_id: Option<u32>) -> Result<(u32, u32, u32)> {
+        self.conn.query_row(
+            "SELECT COUNT(*),
+                    COALESCE(SUM(CASE WHEN status = 'done' THEN completed_at > due_date ELSE 1 END), 0),
+                    COALESCE(SUM(status != 'done'), 0)
+             FROM tasks
+             WHERE due_date IS NOT NULL AND (status = 'done' OR due_date < ?1)
+               AND (?2 IS NULL OR project_id = ?2)",
+            rusqlite::params![now, project_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+    }
+
+    // Replace every source tag with the target tag, returning the number of tasks touched
+    pub fn merge_tags(&self, sources: &[String], target: &str) -> Result<usize> {
+        let placeholders = (1..=sources.len()).map(|i| format!("?{}", i)).collect::<Vec<_>>().join(", ");
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tasks WHERE id IN (SELECT task_id FROM task_tags WHERE tag IN ({}))",
+            TASK_COLUMNS, placeholders
+        ))?;
+        let tasks = stmt
+            .query_map(rusqlite::params_from_iter(sources), Self::map_row_to_task)?
+            .collect::<Result<Vec<_>>>()?;
+
+        db::atomically(&self.conn, || {
+            let mut touched = 0;
+            for mut task in tasks {
+                let renamed = task.tags.iter().map(|t| if sources.contains(t) { target } else { t.as_str() });
+                task.tags = normalize_tags(renamed);
+                task.updated_at = Local::now().timestamp();
+                self.update(&task)?;
+                touched += 1;
+            }
+            Ok(touched)
+        })
+    }
+
+    // Apply a bulk edit all at once, returning the IDs given to the added tasks
+    pub fn apply_changes(&self, updated: &[Task], removed: &[u32], added: &[Task]) -> Result<Vec<u32>> {
+        db::atomically(&self.conn, || {
+            for task in updated {
+                self.update(task)?;
+            }
+            for id in removed {
+                self.delete(*id)?;
+            }
+            added.iter().map(|task| self.insert(task)).collect()
+        })
+    }
+
+    // Open tasks with a due date up to the given timestamp, earliest first
+    pub fn find_open_due_before(&self, until: i64, project_id: Option<u32>) -> Result<Vec<Task>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tasks
+             WHERE status != 'done' AND due_date IS NOT NULL AND due_date <= ?1
+               AND (?2 IS NULL OR project_id = ?2)
+             ORDER BY due_date, id",
+            TASK_COLUMNS
+        ))?;
+        let task_iter = stmt.query_map(rusqlite::params![until, project_id], Self::map_row_to_task)?;
+
+        let mut tasks = Vec::new();
+        for task in task_iter {
+            tasks.push(task?);
+        }
+        Ok(tasks)
+    }
+
+    pub fn find_by_status(&self, status: Status, project_id: Option<u32>) -> Result<Vec<Task>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tasks WHERE status = ?1 AND (?2 IS NULL OR project_id = ?2) ORDER BY id",
+            TASK_COLUMNS
+        ))?;
+        let task_iter = stmt.query_map(rusqlite::params![status, project_id], Self::map_row_to_task)?;
+
+        let mut tasks = Vec::new();
+        for task in task_iter {
+            tasks.push(task?);
+        }
+        Ok(tasks)
+    }
+
+    // Open tasks scheduled to start within the given range, earliest first
+    pub fn find_open_scheduled_between(&self, from: i64, until: i64, project_id: Option<u32>) -> Result<Vec<Task>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tasks
+             WHERE status != 'done' AND scheduled_date >= ?1 AND scheduled_date <= ?2
+               AND (?3 IS NULL OR project_id = ?3)
+             ORDER BY scheduled_date, id",
+            TASK_COLUMNS
+        ))?;
+        let task_iter = stmt.query_map(rusqlite::params![from, until, project_id], Self::map_row_to_task)?;
+
+        let mut tasks = Vec::new();
+        for task in task_iter {
+            tasks.push(task?);
+        }
+        Ok(tasks)
+    }
 }
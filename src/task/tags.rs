@@ -0,0 +1,73 @@
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+pub fn normalize_tags<I, S>(tags: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = normalize_tag(tag.as_ref());
+        if !tag.is_empty() && !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized
+}
+
+pub fn apply_tag_changes(tags: &mut Vec<String>, added: &[String], removed: &[String]) {
+    tags.retain(|t| !removed.contains(t));
+    for tag in added {
+        if !tags.contains(tag) {
+            tags.push(tag.clone());
+        }
+    }
+}
+
+// Value of a namespaced tag under the given key: 'area:backend' or 'area/backend' -> 'backend'
+pub fn tag_value<'a>(tag: &'a str, key: &str) -> Option<&'a str> {
+    let rest = tag.strip_prefix(key)?;
+    rest.strip_prefix(':')
+        .or_else(|| rest.strip_prefix('/'))
+        .filter(|value| !value.is_empty())
+}
+
+// A parent tag also covers its children: 'client' matches 'client/acme' and 'client:acme'
+pub fn tag_matches(tag: &str, key: &str) -> bool {
+    tag == key || tag.strip_prefix(key).is_some_and(|rest| rest.starts_with(['/', ':']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_tags() {
+        let tags = normalize_tags(["Work", " #urgent", "work", ""]);
+        assert_eq!(tags, vec!["work", "urgent"]);
+    }
+
+    #[test]
+    fn test_apply_tag_changes() {
+        let mut tags = vec!["work".to_string(), "later".to_string()];
+        apply_tag_changes(&mut tags, &["urgent".to_string(), "work".to_string()], &["later".to_string()]);
+        assert_eq!(tags, vec!["work", "urgent"]);
+    }
+
+    #[test]
+    fn test_tag_value() {
+        assert_eq!(tag_value("area:backend", "area"), Some("backend"));
+        assert_eq!(tag_value("client/acme/web", "client"), Some("acme/web"));
+        assert_eq!(tag_value("areas:x", "area"), None);
+        assert_eq!(tag_value("area", "area"), None);
+    }
+
+    #[test]
+    fn test_tag_matches_hierarchy() {
+        assert!(tag_matches("client/acme", "client"));
+        assert!(tag_matches("area:backend", "area"));
+        assert!(!tag_matches("clients", "client"));
+    }
+}
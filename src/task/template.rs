@@ -0,0 +1,199 @@
+use crate::project::Project;
+use crate::task::dates::{parse_date, parse_due_date, start_of_day_timestamp};
+use crate::task::{Task, TaskDraft};
+use chrono::{Local, TimeZone};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// A task as it appears in the editor: dates as text and the project by name
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaskFields {
+    // Only set in bulk edits, where it ties an entry to an existing task
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    pub title: String,
+    pub status: String,
+    pub priority: String,
+    pub project: String,
+    pub due: String,
+    pub scheduled: String,
+    pub estimate: String,
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<u32>,
+    pub notes: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Value>,
+}
+
+// Comment block placed above a task template; comments are ignored when parsing
+pub fn template_header(projects: &[Project]) -> String {
+    let project_names = match projects.is_empty() {
+        true => "(no projects yet)".to_string(),
+        false => projects.iter().map(|p| p.name.as_str()).collect::<Vec<_>>().join(", "),
+    };
+
+    [
+        "# Save to apply, or delete everything to cancel. Lines starting with '#' are ignored.".to_string(),
+        "# status:         todo, in_progress, done".to_string(),
+        "# priority:       low, medium, high, urgent".to_string(),
+        format!("# project:        {} (empty for none)", project_names),
+        "# due, scheduled: 2025-08-10, today, tomorrow, +3d or a weekday (empty for none)".to_string(),
+        "# estimate:       45m, 2h, 1h30m".to_string(),
+        "# parent:         add 'parent = <task id>' to make this a subtask".to_string(),
+    ]
+    .join("\n")
+        + "\n\n"
+}
+
+// Several tasks as an array of [[task]] tables
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TaskList {
+    #[serde(default)]
+    pub task: Vec<TaskFields>,
+}
+
+pub fn bulk_template(tasks: &[Task], projects: &[Project]) -> Result<String, toml::ser::Error> {
+    let list = TaskList {
+        task: tasks
+            .iter()
+            .map(|task| TaskFields {
+                id: Some(task.id),
+                ..TaskFields::from_draft(&task.to_draft(), projects)
+            })
+            .collect(),
+    };
+
+    let bulk_help = [
+        "# Each [[task]] is one task: remove an entry to delete that task,",
+        "# or add one without an id to create a task.",
+    ]
+    .join("\n");
+    Ok(template_header(projects) + &bulk_help + "\n\n" + &toml::to_string_pretty(&list)?)
+}
+
+impl TaskFields {
+    pub fn from_draft(draft: &TaskDraft, projects: &[Project]) -> Self {
+        let date = |timestamp: Option<i64>| {
+            timestamp
+                .and_then(|ts| Local.timestamp_opt(ts, 0).single())
+                .map(|time| time.date_naive().to_string())
+                .unwrap_or_default()
+        };
+
+        TaskFields {
+            id: None,
+            title: draft.title.clone(),
+            status: draft.status.clone(),
+            priority: draft.priority.clone(),
+            project: draft
+                .project_id
+                .map(|id| match projects.iter().find(|p| p.id == id) {
+                    Some(project) => project.name.clone(),
+                    None => format!("#{}", id),
+                })
+                .unwrap_or_default(),
+            due: date(draft.due_date),
+            scheduled: date(draft.scheduled_date),
+            estimate: draft.estimate.clone().unwrap_or_default(),
+            tags: draft.tags.clone(),
+            parent: draft.parent_id,
+            notes: draft.notes.clone(),
+            extras: draft.extras.clone(),
+        }
+    }
+
+    pub fn into_draft(self, projects: &[Project]) -> Result<TaskDraft, String> {
+        let due_date = match self.due.trim() {
+            "" => None,
+            due => Some(parse_due_date(due).map_err(|e| format!("due: {}", e))?),
+        };
+        let scheduled_date = match self.scheduled.trim() {
+            "" => None,
+            scheduled => Some(start_of_day_timestamp(parse_date(scheduled).map_err(|e| format!("scheduled: {}", e))?)?),
+        };
+
+        Ok(TaskDraft {
+            title: self.title,
+            status: self.status,
+            tags: self.tags,
+            priority: self.priority,
+            due_date,
+            scheduled_date,
+            estimate: Some(self.estimate).filter(|e| !e.trim().is_empty()),
+            parent_id: self.parent,
+            project_id: project_id(self.project.trim(), projects)?,
+            notes: self.notes,
+            extras: self.extras,
+        })
+    }
+}
+
+// Match a project by name, ignoring case, or by '#id'
+fn project_id(name: &str, projects: &[Project]) -> Result<Option<u32>, String> {
+    if name.is_empty() {
+        return Ok(None);
+    }
+    if let Some(project) = projects.iter().find(|p| p.name == name).or_else(|| {
+        projects.iter().find(|p| p.name.eq_ignore_ascii_case(name))
+    }) {
+        return Ok(Some(project.id));
+    }
+    if let Some(id) = name.strip_prefix('#').and_then(|id| id.parse::<u32>().ok())
+        && projects.iter().any(|p| p.id == id)
+    {
+        return Ok(Some(id));
+    }
+
+    let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+    Err(format!("Unknown project '{}'. Use one of: {}", name, names.join(", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(id: u32, name: &str) -> Project {
+        Project {
+            id,
+            name: name.to_string(),
+            description: None,
+            created_at: 0,
+            updated_at: 0,
+            extras: None,
+        }
+    }
+
+    #[test]
+    fn test_template_round_trip() {
+        let projects = vec![project(1, "work"), project(2, "home")];
+        let mut draft = TaskDraft::new();
+        draft.title = "Write report".to_string();
+        draft.project_id = Some(2);
+        draft.due_date = Some(parse_due_date("2025-08-10").unwrap());
+
+        let toml = draft.to_toml(&projects).unwrap();
+        assert!(toml.contains("# project:        work, home (empty for none)"));
+        assert!(toml.contains("project = \"home\""));
+        assert!(toml.contains("due = \"2025-08-10\""));
+
+        let parsed = TaskDraft::from_toml(&toml, &projects).unwrap();
+        assert_eq!(parsed.project_id, Some(2));
+        assert_eq!(parsed.due_date, draft.due_date);
+        assert_eq!(parsed.scheduled_date, None);
+    }
+
+    #[test]
+    fn test_template_accepts_relative_dates_and_rejects_unknown_projects() {
+        let projects = vec![project(1, "work")];
+        let draft = TaskDraft::from_toml("title = \"Call\"\ndue = \"tomorrow\"\nproject = \"Work\"\n", &projects).unwrap();
+        assert_eq!(draft.project_id, Some(1));
+        assert!(draft.due_date.is_some());
+
+        assert!(TaskDraft::from_toml("title = \"Call\"\nproject = \"gym\"\n", &projects).is_err());
+        assert_eq!(TaskDraft::from_toml("title = \"Call\"\nproject = \"#1\"\n", &projects).unwrap().project_id, Some(1));
+        assert!(TaskDraft::from_toml("title = \"Call\"\nproject = \"#99\"\n", &projects).is_err());
+        assert!(TaskDraft::from_toml("title = \"Call\"\ndue = \"someday\"\n", &projects).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use crate::config::UrgencyConfig;
+use crate::task::model::{Priority, Status, Task};
+use crate::task::tags::tag_matches;
+use std::collections::{HashMap, HashSet};
+
+const DAY: f64 = 24.0 * 60.0 * 60.0;
+
+// Scores tasks against the whole task list, which is needed for the subtask factors
+pub struct UrgencyScorer {
+    config: UrgencyConfig,
+    open_children: HashMap<u32, u32>,
+    open_ids: HashSet<u32>,
+    project_names: HashMap<u32, String>,
+    now: i64,
+}
+
+impl UrgencyScorer {
+    pub fn new(config: UrgencyConfig, tasks: &[Task], project_names: HashMap<u32, String>, now: i64) -> Self {
+        let mut open_children = HashMap::new();
+        let mut open_ids = HashSet::new();
+        for task in tasks.iter().filter(|t| !matches!(t.status, Status::Done)) {
+            open_ids.insert(task.id);
+            if let Some(parent_id) = task.parent_id {
+                *open_children.entry(parent_id).or_insert(0) += 1;
+            }
+        }
+
+        UrgencyScorer { config, open_children, open_ids, project_names, now }
+    }
+
+    // A task waiting on its own open subtasks
+    pub fn is_blocked(&self, task: &Task) -> bool {
+        self.open_children.contains_key(&task.id)
+    }
+
+    // Open, started and not waiting on subtasks
+    pub fn is_actionable(&self, task: &Task) -> bool {
+        !matches!(task.status, Status::Done) && !task.is_waiting(self.now) && !self.is_blocked(task)
+    }
+
+    // Each contributing factor with its weighted value, largest first
+    pub fn terms(&self, task: &Task) -> Vec<(String, f64)> {
+        let config = &self.config;
+        let mut terms = Vec::new();
+
+        let priority = match task.priority {
+            Priority::Urgent => config.priority_urgent,
+            Priority::High => config.priority_high,
+            Priority::Medium => config.priority_medium,
+            Priority::Low => config.priority_low,
+        };
+        terms.push((format!("priority {}", task.priority), priority));
+
+        if let Some(due) = task.due_date {
+            terms.push(("due date".to_string(), config.due * due_factor(due, self.now)));
+        }
+
+        // Age grows linearly to its full weight after a year
+        let age_days = (self.now - task.created_at).max(0) as f64 / DAY;
+        terms.push(("age".to_string(), config.age * (age_days / 365.0).min(1.0)));
+
+        if matches!(task.status, Status::InProgress) {
+            terms.push(("in progress".to_string(), config.in_progress));
+        }
+
+        if self.is_blocked(task) {
+            terms.push(("open subtasks".to_string(), config.blocked));
+        }
+
+        if task.parent_id.is_some_and(|parent_id| self.open_ids.contains(&parent_id)) {
+            terms.push(("blocks parent".to_string(), config.blocking));
+        }
+
+        for (tag, coefficient) in &config.tags {
+            if task.tags.iter().any(|t| tag_matches(t, tag)) {
+                terms.push((format!("tag {}", tag), *coefficient));
+            }
+        }
+
+        if let Some(coefficient) = task
+            .project_id
+            .and_then(|id| self.project_names.get(&id))
+            .and_then(|name| config.projects.get(name))
+        {
+            terms.push(("project".to_string(), *coefficient));
+        }
+
+        terms.retain(|(_, value)| *value != 0.0);
+        terms.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()));
+        terms
+    }
+
+    pub fn score(&self, task: &Task) -> f64 {
+        self.terms(task).iter().map(|(_, value)| value).sum()
+    }
+}
+
+// 1.0 from a week overdue, falling linearly to 0.2 two weeks out, as in Taskwarrior
+fn due_factor(due: i64, now: i64) -> f64 {
+    let days_overdue = (now - due) as f64 / DAY;
+    if days_overdue >= 7.0 {
+        1.0
+    } else if days_overdue >= -14.0 {
+        ((days_overdue + 14.0) * 0.8 / 21.0) + 0.2
+    } else {
+        0.2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::TaskDraft;
+
+    fn task(id: u32, priority: &str) -> Task {
+        let mut draft = TaskDraft::new();
+        draft.title = format!("Task {}", id);
+        draft.priority = priority.to_string();
+        let mut task = draft.into_task().unwrap();
+        task.id = id;
+        task
+    }
+
+    #[test]
+    fn test_due_factor_range() {
+        let now = 100 * DAY as i64;
+        assert_eq!(due_factor(now - 8 * DAY as i64, now), 1.0);
+        assert_eq!(due_factor(now + 30 * DAY as i64, now), 0.2);
+        assert!((due_factor(now, now) - (14.0 * 0.8 / 21.0 + 0.2)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_urgency_factors() {
+        let mut parent = task(1, "low");
+        let mut child = task(2, "high");
+        child.parent_id = Some(1);
+        child.tags = vec!["next".to_string()];
+        parent.project_id = Some(7);
+
+        let mut config = UrgencyConfig::default();
+        config.projects.insert("work".to_string(), 2.0);
+        let now = child.created_at;
+        let tasks = vec![parent, child];
+        let scorer = UrgencyScorer::new(config, &tasks, HashMap::from([(7, "work".to_string())]), now);
+
+        assert!(scorer.is_blocked(&tasks[0]));
+        assert!(!scorer.is_actionable(&tasks[0]));
+        assert!((scorer.score(&tasks[0]) - (1.8 - 5.0 + 2.0)).abs() < 1e-9);
+        assert!((scorer.score(&tasks[1]) - (6.0 + 1.0 + 15.0)).abs() < 1e-9);
+        assert_eq!(scorer.terms(&tasks[1])[0].0, "tag next");
+    }
+}
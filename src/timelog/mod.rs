@@ -0,0 +1,5 @@
+pub mod model;
+pub mod store;
+
+pub use model::TimeEntry;
+pub use store::TimeStore;
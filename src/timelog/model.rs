@@ -0,0 +1,20 @@
+use chrono::{DateTime, Local, TimeZone};
+
+#[derive(Debug)]
+pub struct TimeEntry {
+    pub id: u32,
+    pub task_id: u32,
+    pub started_at: i64,
+    pub ended_at: Option<i64>,
+}
+
+impl TimeEntry {
+    pub fn started_at_datetime(&self) -> DateTime<Local> {
+        Local.timestamp_opt(self.started_at, 0).unwrap()
+    }
+
+    // Running timers count up to the given moment
+    pub fn duration_seconds(&self, now: i64) -> i64 {
+        (self.ended_at.unwrap_or(now) - self.started_at).max(0)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::db;
+use crate::timelog::model::TimeEntry;
+use directories::ProjectDirs;
+use rusqlite::{Connection, Result};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+pub struct TimeStore {
+    conn: Rc<Connection>,
+}
+
+impl TimeStore {
+    fn map_row_to_entry(row: &rusqlite::Row) -> Result<TimeEntry> {
+        Ok(TimeEntry {
+            id: row.get("id")?,
+            task_id: row.get("task_id")?,
+            started_at: row.get("started_at")?,
+            ended_at: row.get("ended_at")?,
+        })
+    }
+
+    pub fn new() -> Result<Self> {
+        let db_path = Self::get_db_path();
+        Self::with_connection(db::connect(&db_path)?)
+    }
+
+    // Share another store's connection, so these writes join its transaction
+    pub fn with_connection(conn: Rc<Connection>) -> Result<Self> {
+        let store = TimeStore { conn };
+        store.init_tables()?;
+        Ok(store)
+    }
+
+    fn get_db_path() -> PathBuf {
+        ProjectDirs::from("com", "you", "clara")
+            .expect("no valid home dir")
+            .data_local_dir()
+            .join("clara.db")
+    }
+
+    fn init_tables(&self) -> Result<()> {
+        // The partial unique index allows at most one running timer
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS time_entries (
+                id           INTEGER PRIMARY KEY,
+                task_id      INTEGER NOT NULL,
+                started_at   INTEGER NOT NULL,
+                ended_at     INTEGER,
+                source       TEXT NOT NULL DEFAULT 'timer',
+                FOREIGN KEY(task_id) REFERENCES tasks(id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_time_entries_task ON time_entries(task_id);
+            CREATE UNIQUE INDEX IF NOT EXISTS idx_time_entries_active
+                ON time_entries((ended_at IS NULL)) WHERE ended_at IS NULL;",
+        )?;
+        Ok(())
+    }
+
+    pub fn start(&self, task_id: u32, now: i64) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO time_entries (task_id, started_at, source) VALUES (?1, ?2, 'timer')",
+            rusqlite::params![task_id, now],
+        )?;
+        Ok(())
+    }
+
+    pub fn active(&self) -> Result<Option<TimeEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, task_id, started_at, ended_at FROM time_entries WHERE ended_at IS NULL",
+        )?;
+        let mut entry_iter = stmt.query_map([], Self::map_row_to_entry)?;
+
+        match entry_iter.next() {
+            Some(entry) => Ok(Some(entry?)),
+            None => Ok(None),
+        }
+    }
+
+    // Stop the running timer, if any, and return the finished entry
+    pub fn stop(&self, now: i64) -> Result<Option<TimeEntry>> {
+        let mut entry = match self.active()? {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+
+        self.conn.execute(
+            "UPDATE time_entries SET ended_at = ?1 WHERE id = ?2",
+            rusqlite::params![now, entry.id],
+        )?;
+        entry.ended_at = Some(now);
+        Ok(Some(entry))
+    }
+
+    // Drops every entry of a task, including a timer that is still running
+    pub fn delete_for_task(&self, task_id: u32) -> Result<()> {
+        self.conn.execute("DELETE FROM time_entries WHERE task_id = ?1", [task_id])?;
+        Ok(())
+    }
+
+    // Record already finished work ending at the given moment
+    pub fn log(&self, task_id: u32, seconds: i64, ended_at: i64, source: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO time_entries (task_id, started_at, ended_at, source) VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![task_id, ended_at - seconds, ended_at, source],
+        )?;
+        Ok(())
+    }
+
+    // Total tracked seconds per task, including a running timer
+    pub fn totals_by_task(&self, now: i64) -> Result<HashMap<u32, i64>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT task_id, SUM(COALESCE(ended_at, ?1) - started_at) FROM time_entries GROUP BY task_id",
+        )?;
+        let total_iter = stmt.query_map([now], |row| Ok((row.get(0)?, row.get(1)?)))?;
+
+        let mut totals = HashMap::new();
+        for total in total_iter {
+            let (task_id, seconds) = total?;
+            totals.insert(task_id, seconds);
+        }
+        Ok(totals)
+    }
+
+    pub fn entries_between(&self, from: i64, until: i64) -> Result<Vec<TimeEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, task_id, started_at, ended_at FROM time_entries
+             WHERE started_at >= ?1 AND started_at <= ?2 ORDER BY started_at",
+        )?;
+        let entry_iter = stmt.query_map([from, until], Self::map_row_to_entry)?;
+
+        let mut entries = Vec::new();
+        for entry in entry_iter {
+            entries.push(entry?);
+        }
+        Ok(entries)
+    }
+
+    // (task_id, pomodoros since the given moment, pomodoros overall), busiest first
+    pub fn pomodoro_counts(&self, since: i64) -> Result<Vec<(u32, u32, u32)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT task_id, SUM(started_at >= ?1), COUNT(*) FROM time_entries
+             WHERE source = 'pomodoro' GROUP BY task_id ORDER BY COUNT(*) DESC, task_id",
+        )?;
+        let count_iter = stmt.query_map([since], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+
+        let mut counts = Vec::new();
+        for count in count_iter {
+            counts.push(count?);
+        }
+        Ok(counts)
+    }
+}
@@ -0,0 +1,466 @@
+use crate::cli::commands::add::task_from_text;
+use crate::cli::filter::{parse_filter, TaskFilter};
+use crate::project::{Project, ProjectStore};
+use crate::task::model::Priority;
+use crate::task::tags::tag_matches;
+use crate::task::{Status, Task, TaskStore};
+use chrono::Local;
+use std::collections::HashSet;
+
+pub struct TaskTree {
+    pub task: Task,
+    pub children: Vec<TaskTree>,
+}
+
+pub struct Row<'a> {
+    pub task: &'a Task,
+    pub depth: usize,
+    pub has_children: bool,
+    pub collapsed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Focus {
+    Tree,
+    Sidebar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    Normal,
+    Filter,
+    EditTitle(u32),
+    AddTask { parent_id: Option<u32> },
+}
+
+// What the filter bar narrows the tree down to
+enum Matcher {
+    All,
+    Text(String),
+    Filter(TaskFilter),
+}
+
+impl Matcher {
+    fn parse(filter: &str) -> Result<Self, String> {
+        let filter = filter.trim();
+        if filter.is_empty() {
+            Ok(Matcher::All)
+        } else if filter.starts_with('/') {
+            Ok(Matcher::Filter(parse_filter(filter)?))
+        } else {
+            Ok(Matcher::Text(filter.to_lowercase()))
+        }
+    }
+
+    fn is_all(&self) -> bool {
+        matches!(self, Matcher::All)
+    }
+
+    fn matches(&self, task: &Task) -> bool {
+        match self {
+            Matcher::All => true,
+            Matcher::Text(text) => {
+                task.title.to_lowercase().contains(text) || task.tags.iter().any(|t| t.contains(text.as_str()))
+            }
+            Matcher::Filter(filter) => {
+                filter.matches(task)
+                    && filter.tags.iter().all(|key| task.tags.iter().any(|t| tag_matches(t, key)))
+            }
+        }
+    }
+}
+
+// Flatten the tree into display rows. Without a filter collapsed nodes hide their
+// children; with one, matching tasks are shown together with their ancestors.
+pub fn visible_rows<'a>(tree: &'a [TaskTree], collapsed: &HashSet<u32>, filter: &str) -> Result<Vec<Row<'a>>, String> {
+    let matcher = Matcher::parse(filter)?;
+    let mut rows = Vec::new();
+    for node in tree {
+        push_rows(node, 0, collapsed, &matcher, &mut rows);
+    }
+    Ok(rows)
+}
+
+fn push_rows<'a>(node: &'a TaskTree, depth: usize, collapsed: &HashSet<u32>, matcher: &Matcher, rows: &mut Vec<Row<'a>>) -> bool {
+    let row = Row {
+        task: &node.task,
+        depth,
+        has_children: !node.children.is_empty(),
+        collapsed: matcher.is_all() && collapsed.contains(&node.task.id),
+    };
+
+    if matcher.is_all() {
+        let show_children = !row.collapsed;
+        rows.push(row);
+        if show_children {
+            for child in &node.children {
+                push_rows(child, depth + 1, collapsed, matcher, rows);
+            }
+        }
+        return true;
+    }
+
+    let mut child_rows = Vec::new();
+    let mut child_matched = false;
+    for child in &node.children {
+        child_matched |= push_rows(child, depth + 1, collapsed, matcher, &mut child_rows);
+    }
+
+    if child_matched || matcher.matches(&node.task) {
+        rows.push(row);
+        rows.extend(child_rows);
+        true
+    } else {
+        false
+    }
+}
+
+pub fn next_status(status: &Status) -> Status {
+    match status {
+        Status::Todo => Status::InProgress,
+        Status::InProgress => Status::Done,
+        Status::Done => Status::Todo,
+    }
+}
+
+pub fn shift_priority(priority: &Priority, up: bool) -> Priority {
+    match (priority, up) {
+        (Priority::Low, true) | (Priority::High, false) => Priority::Medium,
+        (Priority::Medium, true) | (Priority::Urgent, false) => Priority::High,
+        (Priority::High, true) | (Priority::Urgent, true) => Priority::Urgent,
+        (Priority::Low, false) | (Priority::Medium, false) => Priority::Low,
+    }
+}
+
+pub struct App {
+    store: TaskStore,
+    pub projects: Vec<Project>,
+    // 0 is "All tasks", then one entry per project
+    pub project_index: usize,
+    pub sidebar_selected: usize,
+    pub tree: Vec<TaskTree>,
+    pub collapsed: HashSet<u32>,
+    pub selected: usize,
+    pub filter: String,
+    pub focus: Focus,
+    pub mode: Mode,
+    pub input: String,
+    pub message: Option<String>,
+    pub should_quit: bool,
+}
+
+impl App {
+    pub fn new(project_id: Option<u32>) -> Result<Self, Box<dyn std::error::Error>> {
+        let projects = ProjectStore::new()?.list()?;
+        let project_index = project_id
+            .and_then(|id| projects.iter().position(|p| p.id == id))
+            .map(|i| i + 1)
+            .unwrap_or(0);
+
+        let mut app = App {
+            store: TaskStore::new()?,
+            projects,
+            project_index,
+            sidebar_selected: project_index,
+            tree: Vec::new(),
+            collapsed: HashSet::new(),
+            selected: 0,
+            filter: String::new(),
+            focus: Focus::Tree,
+            mode: Mode::Normal,
+            input: String::new(),
+            message: None,
+            should_quit: false,
+        };
+        app.reload()?;
+        Ok(app)
+    }
+
+    pub fn project_id(&self) -> Option<u32> {
+        self.project_index.checked_sub(1).map(|i| self.projects[i].id)
+    }
+
+    pub fn project_name(&self) -> &str {
+        match self.project_index.checked_sub(1) {
+            Some(i) => &self.projects[i].name,
+            None => "All tasks",
+        }
+    }
+
+    pub fn rows(&self) -> Vec<Row<'_>> {
+        visible_rows(&self.tree, &self.collapsed, &self.filter).unwrap_or_default()
+    }
+
+    pub fn filter_error(&self) -> Option<String> {
+        visible_rows(&self.tree, &self.collapsed, &self.filter).err()
+    }
+
+    pub fn selected_id(&self) -> Option<u32> {
+        self.rows().get(self.selected).map(|row| row.task.id)
+    }
+
+    // Rebuild the tree from the store, keeping the cursor on the same task
+    pub fn reload(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let selected_id = self.selected_id();
+
+        let roots = match self.project_id() {
+            Some(project_id) => self.store.find_root_tasks_by_project(project_id)?,
+            None => self.store.find_root_tasks()?,
+        };
+        let mut seen = HashSet::new();
+        let mut tree = Vec::new();
+        for root in roots {
+            tree.push(self.build_tree(root, &mut seen)?);
+        }
+        self.tree = tree;
+
+        self.select_id(selected_id);
+        Ok(())
+    }
+
+    fn build_tree(&self, task: Task, seen: &mut HashSet<u32>) -> Result<TaskTree, Box<dyn std::error::Error>> {
+        // Guard against parent cycles in the stored data
+        seen.insert(task.id);
+        let mut children = Vec::new();
+        for child in self.store.find_children(task.id)? {
+            if !seen.contains(&child.id) {
+                children.push(self.build_tree(child, seen)?);
+            }
+        }
+        Ok(TaskTree { task, children })
+    }
+
+    fn select_id(&mut self, id: Option<u32>) {
+        let rows = self.rows();
+        let index = id.and_then(|id| rows.iter().position(|row| row.task.id == id));
+        let len = rows.len();
+        self.selected = match index {
+            Some(index) => index,
+            None => self.selected.min(len.saturating_sub(1)),
+        };
+    }
+
+    pub fn move_selection(&mut self, delta: isize) {
+        let len = self.rows().len();
+        if len == 0 {
+            self.selected = 0;
+            return;
+        }
+        self.selected = (self.selected as isize + delta).clamp(0, len as isize - 1) as usize;
+    }
+
+    pub fn select_last(&mut self) {
+        self.selected = self.rows().len().saturating_sub(1);
+    }
+
+    pub fn toggle_collapse(&mut self) {
+        let id = match self.rows().get(self.selected) {
+            Some(row) if row.has_children => row.task.id,
+            _ => return,
+        };
+        if !self.collapsed.remove(&id) {
+            self.collapsed.insert(id);
+        }
+    }
+
+    // Collapse the selected node, or jump to its parent when there is nothing to fold
+    pub fn collapse_or_parent(&mut self) {
+        let rows = self.rows();
+        let row = match rows.get(self.selected) {
+            Some(row) => row,
+            None => return,
+        };
+
+        if row.has_children && !row.collapsed {
+            let id = row.task.id;
+            self.collapsed.insert(id);
+        } else if let Some(parent) = rows[..self.selected].iter().rposition(|r| r.depth < row.depth) {
+            self.selected = parent;
+        }
+    }
+
+    pub fn expand(&mut self) {
+        if let Some(id) = self.selected_id() {
+            self.collapsed.remove(&id);
+        }
+    }
+
+    fn selected_task(&self) -> Option<Task> {
+        let id = self.selected_id()?;
+        self.store.find_by_id(id).ok().flatten()
+    }
+
+    pub fn cycle_status(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(task) = self.selected_task() {
+            let status = next_status(&task.status);
+            self.message = Some(format!("Task {} is now {}", task.id, status));
+            self.store.update_status(task.id, status)?;
+            self.reload()?;
+        }
+        Ok(())
+    }
+
+    pub fn toggle_done(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(task) = self.selected_task() {
+            let status = match task.status {
+                Status::Done => Status::Todo,
+                _ => Status::Done,
+            };
+            self.message = Some(format!("Task {} is now {}", task.id, status));
+            self.store.update_status(task.id, status)?;
+            self.reload()?;
+        }
+        Ok(())
+    }
+
+    pub fn shift_priority(&mut self, up: bool) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(mut task) = self.selected_task() {
+            task.priority = shift_priority(&task.priority, up);
+            task.updated_at = Local::now().timestamp();
+            self.message = Some(format!("Task {} priority is now {}", task.id, task.priority));
+            self.store.update(&task)?;
+            self.reload()?;
+        }
+        Ok(())
+    }
+
+    pub fn start_edit(&mut self) {
+        if let Some(task) = self.selected_task() {
+            self.input = task.title;
+            self.mode = Mode::EditTitle(task.id);
+        }
+    }
+
+    pub fn start_add(&mut self, as_subtask: bool) {
+        let parent_id = if as_subtask {
+            self.selected_id()
+        } else {
+            // A new sibling shares the selected task's parent
+            self.selected_task().and_then(|task| task.parent_id)
+        };
+        self.input.clear();
+        self.mode = Mode::AddTask { parent_id };
+    }
+
+    pub fn start_filter(&mut self) {
+        self.input = self.filter.clone();
+        self.mode = Mode::Filter;
+    }
+
+    pub fn cancel_input(&mut self) {
+        if self.mode == Mode::Filter {
+            self.filter.clear();
+        }
+        self.input.clear();
+        self.mode = Mode::Normal;
+    }
+
+    // Live filtering: the filter follows the input as it is typed
+    pub fn input_changed(&mut self) {
+        if self.mode == Mode::Filter {
+            self.filter = self.input.clone();
+            self.selected = 0;
+        }
+    }
+
+    pub fn submit_input(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let input = std::mem::take(&mut self.input);
+        let mode = std::mem::replace(&mut self.mode, Mode::Normal);
+
+        match mode {
+            Mode::Normal | Mode::Filter => {}
+            Mode::EditTitle(id) => {
+                let title = input.trim();
+                if title.is_empty() {
+                    self.message = Some("Title cannot be empty".to_string());
+                    return Ok(());
+                }
+                if let Some(mut task) = self.store.find_by_id(id)? {
+                    task.title = title.to_string();
+                    task.updated_at = Local::now().timestamp();
+                    self.store.update(&task)?;
+                    self.message = Some(format!("Task {} renamed", id));
+                }
+                self.reload()?;
+            }
+            Mode::AddTask { parent_id } => {
+                if input.trim().is_empty() {
+                    return Ok(());
+                }
+                let task = task_from_text(&self.store, &input, parent_id, self.project_id())?;
+                let id = self.store.insert(&task)?;
+                if let Some(parent_id) = task.parent_id {
+                    self.collapsed.remove(&parent_id);
+                }
+                self.message = Some(format!("Task {} added", id));
+                self.reload()?;
+                self.select_id(Some(id));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn select_project(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.project_index = self.sidebar_selected;
+        self.selected = 0;
+        self.focus = Focus::Tree;
+        self.reload()
+    }
+
+    pub fn move_sidebar(&mut self, delta: isize) {
+        let len = self.projects.len() as isize + 1;
+        self.sidebar_selected = (self.sidebar_selected as isize + delta).clamp(0, len - 1) as usize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::TaskDraft;
+
+    fn node(id: u32, title: &str, children: Vec<TaskTree>) -> TaskTree {
+        let mut draft = TaskDraft::new();
+        draft.title = title.to_string();
+        let mut task = draft.into_task().unwrap();
+        task.id = id;
+        TaskTree { task, children }
+    }
+
+    fn sample_tree() -> Vec<TaskTree> {
+        vec![
+            node(1, "Release", vec![node(2, "Write notes", vec![]), node(3, "Tag build", vec![node(4, "Sign", vec![])])]),
+            node(5, "Groceries", vec![]),
+        ]
+    }
+
+    fn ids(rows: &[Row]) -> Vec<u32> {
+        rows.iter().map(|row| row.task.id).collect()
+    }
+
+    #[test]
+    fn test_collapsed_nodes_hide_children() {
+        let tree = sample_tree();
+        let rows = visible_rows(&tree, &HashSet::new(), "").unwrap();
+        assert_eq!(ids(&rows), vec![1, 2, 3, 4, 5]);
+        assert_eq!(rows[3].depth, 2);
+
+        let rows = visible_rows(&tree, &HashSet::from([3]), "").unwrap();
+        assert_eq!(ids(&rows), vec![1, 2, 3, 5]);
+        assert!(rows[2].collapsed);
+    }
+
+    #[test]
+    fn test_filter_keeps_ancestors_of_matches() {
+        let tree = sample_tree();
+        let rows = visible_rows(&tree, &HashSet::from([1]), "sign").unwrap();
+        assert_eq!(ids(&rows), vec![1, 3, 4]);
+        assert!(visible_rows(&tree, &HashSet::new(), "/color red").is_err());
+    }
+
+    #[test]
+    fn test_status_and_priority_cycles() {
+        assert!(matches!(next_status(&Status::Done), Status::Todo));
+        assert!(matches!(shift_priority(&Priority::Urgent, true), Priority::Urgent));
+        assert!(matches!(shift_priority(&Priority::Medium, false), Priority::Low));
+    }
+}
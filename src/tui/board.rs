@@ -0,0 +1,144 @@
+use crate::cli::commands::board::{board_columns, card_text, column_index, column_status, COLUMN_TITLES};
+use crate::repl::command_handler::ReplContext;
+use crate::task::{Task, TaskStore};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+
+const HELP: &str = "h/l column  j/k card  H/L or </> move card  r reload  q quit";
+
+struct Board {
+    store: TaskStore,
+    project_id: Option<u32>,
+    title: String,
+    columns: [Vec<Task>; 3],
+    column: usize,
+    selected: [usize; 3],
+    message: Option<String>,
+}
+
+impl Board {
+    fn reload(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.columns = board_columns(&self.store, self.project_id)?;
+        for (selected, column) in self.selected.iter_mut().zip(&self.columns) {
+            *selected = (*selected).min(column.len().saturating_sub(1));
+        }
+        Ok(())
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.columns[self.column].len() as isize;
+        let selected = &mut self.selected[self.column];
+        *selected = (*selected as isize + delta).clamp(0, (len - 1).max(0)) as usize;
+    }
+
+    fn move_column(&mut self, delta: isize) {
+        self.column = (self.column as isize + delta).clamp(0, 2) as usize;
+    }
+
+    // Move the selected card to the neighbouring column and keep it selected there
+    fn move_card(&mut self, delta: isize) -> Result<(), Box<dyn std::error::Error>> {
+        let target = self.column as isize + delta;
+        if !(0..3).contains(&target) {
+            return Ok(());
+        }
+        let (id, title) = match self.columns[self.column].get(self.selected[self.column]) {
+            Some(task) => (task.id, task.title.clone()),
+            None => return Ok(()),
+        };
+
+        let status = column_status(target as usize);
+        self.message = Some(format!("Moved [{}] {} to {}", id, title, status));
+        self.store.update_status(id, status)?;
+        self.reload()?;
+
+        if let Some(task) = self.columns.iter().flatten().find(|t| t.id == id) {
+            self.column = column_index(&task.status);
+            self.selected[self.column] = self.columns[self.column].iter().position(|t| t.id == id).unwrap_or(0);
+        }
+        Ok(())
+    }
+}
+
+pub fn run_board(context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let mut board = Board {
+        store: TaskStore::new()?,
+        project_id: context.current_project.as_ref().map(|p| p.id),
+        title: match &context.current_project {
+            Some(project) => format!("Board: {}", project.name),
+            None => "Board: all tasks".to_string(),
+        },
+        columns: Default::default(),
+        column: 0,
+        selected: [0; 3],
+        message: None,
+    };
+    board.reload()?;
+
+    let mut terminal = ratatui::init();
+    let result = run_loop(&mut terminal, &mut board);
+    ratatui::restore();
+    result
+}
+
+fn run_loop(terminal: &mut DefaultTerminal, board: &mut Board) -> Result<(), Box<dyn std::error::Error>> {
+    loop {
+        terminal.draw(|frame| draw(frame, board))?;
+
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+
+        let quit = matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+            || (key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL));
+        if quit {
+            return Ok(());
+        }
+
+        // Store errors are shown in the status line rather than leaving the board
+        if let Err(e) = handle_key(board, key.code) {
+            board.message = Some(format!("Error: {}", e));
+        }
+    }
+}
+
+fn handle_key(board: &mut Board, code: KeyCode) -> Result<(), Box<dyn std::error::Error>> {
+    match code {
+        KeyCode::Char('h') | KeyCode::Left => board.move_column(-1),
+        KeyCode::Char('l') | KeyCode::Right => board.move_column(1),
+        KeyCode::Char('j') | KeyCode::Down => board.move_selection(1),
+        KeyCode::Char('k') | KeyCode::Up => board.move_selection(-1),
+        KeyCode::Char('H') | KeyCode::Char('<') => board.move_card(-1)?,
+        KeyCode::Char('L') | KeyCode::Char('>') => board.move_card(1)?,
+        KeyCode::Char('r') => board.reload()?,
+        _ => {}
+    }
+    Ok(())
+}
+
+fn draw(frame: &mut Frame, board: &Board) {
+    let [main, status, help] = Layout::vertical([Constraint::Min(0), Constraint::Length(1), Constraint::Length(1)])
+        .areas(frame.area());
+    let areas = Layout::horizontal([Constraint::Ratio(1, 3); 3]).split(main);
+
+    for (i, (tasks, area)) in board.columns.iter().zip(areas.iter()).enumerate() {
+        let focused = i == board.column;
+        let items: Vec<ListItem> = tasks.iter().map(|t| ListItem::new(card_text(t))).collect();
+        let block = Block::bordered()
+            .title(format!(" {} ({}) ", COLUMN_TITLES[i], tasks.len()))
+            .border_style(if focused { Style::new().fg(Color::Cyan) } else { Style::new() });
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(if focused { Style::new().reversed() } else { Style::new() });
+
+        let mut state = ListState::default().with_selected((!tasks.is_empty()).then_some(board.selected[i]));
+        frame.render_stateful_widget(list, *area, &mut state);
+    }
+
+    let message = board.message.clone().unwrap_or_else(|| board.title.clone());
+    frame.render_widget(Paragraph::new(message), status);
+    frame.render_widget(Paragraph::new(HELP).dark_gray(), help);
+}
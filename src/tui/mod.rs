@@ -0,0 +1,101 @@
+pub mod app;
+pub mod board;
+pub mod ui;
+
+use crate::repl::command_handler::ReplContext;
+use app::{App, Focus, Mode};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::DefaultTerminal;
+
+pub fn run_tui(context: &ReplContext) -> Result<(), Box<dyn std::error::Error>> {
+    let mut app = App::new(context.current_project.as_ref().map(|p| p.id))?;
+
+    let mut terminal = ratatui::init();
+    let result = run_loop(&mut terminal, &mut app);
+    ratatui::restore();
+    result
+}
+
+fn run_loop(terminal: &mut DefaultTerminal, app: &mut App) -> Result<(), Box<dyn std::error::Error>> {
+    while !app.should_quit {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            // Store errors are shown in the status line rather than leaving the TUI
+            if let Err(e) = handle_key(app, key) {
+                app.message = Some(format!("Error: {}", e));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn handle_key(app: &mut App, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        app.should_quit = true;
+        return Ok(());
+    }
+
+    if app.mode != Mode::Normal {
+        match key.code {
+            KeyCode::Esc => app.cancel_input(),
+            KeyCode::Enter => app.submit_input()?,
+            KeyCode::Backspace => {
+                app.input.pop();
+                app.input_changed();
+            }
+            KeyCode::Char(c) => {
+                app.input.push(c);
+                app.input_changed();
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    app.message = None;
+
+    if app.focus == Focus::Sidebar {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.move_sidebar(1),
+            KeyCode::Char('k') | KeyCode::Up => app.move_sidebar(-1),
+            KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Char('l') | KeyCode::Right => app.select_project()?,
+            KeyCode::Tab | KeyCode::Esc => app.focus = Focus::Tree,
+            KeyCode::Char('q') => app.should_quit = true,
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    match key.code {
+        KeyCode::Char('q') => app.should_quit = true,
+        KeyCode::Char('j') | KeyCode::Down => app.move_selection(1),
+        KeyCode::Char('k') | KeyCode::Up => app.move_selection(-1),
+        KeyCode::PageDown => app.move_selection(10),
+        KeyCode::PageUp => app.move_selection(-10),
+        KeyCode::Char('g') | KeyCode::Home => app.selected = 0,
+        KeyCode::Char('G') | KeyCode::End => app.select_last(),
+        KeyCode::Char('h') | KeyCode::Left => app.collapse_or_parent(),
+        KeyCode::Char('l') | KeyCode::Right => app.expand(),
+        KeyCode::Char(' ') | KeyCode::Enter => app.toggle_collapse(),
+        KeyCode::Char('s') => app.cycle_status()?,
+        KeyCode::Char('x') => app.toggle_done()?,
+        KeyCode::Char('+') | KeyCode::Char('=') => app.shift_priority(true)?,
+        KeyCode::Char('-') => app.shift_priority(false)?,
+        KeyCode::Char('e') => app.start_edit(),
+        KeyCode::Char('a') => app.start_add(false),
+        KeyCode::Char('A') => app.start_add(true),
+        KeyCode::Char('/') => app.start_filter(),
+        KeyCode::Esc => app.filter.clear(),
+        KeyCode::Char('r') => app.reload()?,
+        KeyCode::Tab => {
+            app.sidebar_selected = app.project_index;
+            app.focus = Focus::Sidebar;
+        }
+        _ => {}
+    }
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use crate::cli::format::{relative_due, status_emoji};
+use crate::task::model::Priority;
+use crate::task::Status;
+use crate::tui::app::{App, Focus, Mode, Row};
+use chrono::Local;
+use ratatui::layout::{Constraint, Layout, Position};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+
+const HELP: &str = "j/k move  h/l fold  space toggle  s status  x done  +/- priority  e edit  a add  A subtask  / filter  tab projects  q quit";
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [main, input, help] = Layout::vertical([Constraint::Min(0), Constraint::Length(1), Constraint::Length(1)])
+        .areas(frame.area());
+    let [sidebar, tree] = Layout::horizontal([Constraint::Length(24), Constraint::Min(0)]).areas(main);
+
+    draw_sidebar(frame, app, sidebar);
+    draw_tree(frame, app, tree);
+    draw_input(frame, app, input);
+    frame.render_widget(Paragraph::new(HELP).dark_gray(), help);
+}
+
+fn border_style(focused: bool) -> Style {
+    if focused {
+        Style::new().fg(Color::Cyan)
+    } else {
+        Style::new()
+    }
+}
+
+fn draw_sidebar(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let names = std::iter::once("All tasks").chain(app.projects.iter().map(|p| p.name.as_str()));
+    let items: Vec<ListItem> = names
+        .enumerate()
+        .map(|(i, name)| {
+            let marker = if i == app.project_index { "● " } else { "  " };
+            ListItem::new(format!("{}{}", marker, name))
+        })
+        .collect();
+
+    let focused = app.focus == Focus::Sidebar;
+    let list = List::new(items)
+        .block(Block::bordered().title(" Projects ").border_style(border_style(focused)))
+        .highlight_style(if focused { Style::new().reversed() } else { Style::new() });
+
+    let mut state = ListState::default().with_selected(Some(app.sidebar_selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_tree(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let rows = app.rows();
+    let items: Vec<ListItem> = rows.iter().map(task_line).map(ListItem::new).collect();
+
+    let mut title = format!(" {} ({}) ", app.project_name(), rows.len());
+    if !app.filter.is_empty() {
+        title = format!("{}─ filter: {} ", title, app.filter);
+    }
+
+    let focused = app.focus == Focus::Tree;
+    let list = List::new(items)
+        .block(Block::bordered().title(title).border_style(border_style(focused)))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+
+    let mut state = ListState::default().with_selected((!rows.is_empty()).then_some(app.selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn task_line<'a>(row: &Row<'a>) -> Line<'a> {
+    let task = row.task;
+    let fold = match (row.has_children, row.collapsed) {
+        (false, _) => "  ",
+        (true, true) => "▸ ",
+        (true, false) => "▾ ",
+    };
+
+    let done = matches!(task.status, Status::Done);
+    let title_style = if done { Style::new().dark_gray() } else { Style::new() };
+    let mut spans = vec![
+        Span::raw(format!("{}{}{} ", "  ".repeat(row.depth), fold, status_emoji(&task.status))),
+        Span::styled(format!("[{}] {}", task.id, task.title), title_style),
+    ];
+
+    let priority_color = match task.priority {
+        Priority::Urgent => Some(Color::Red),
+        Priority::High => Some(Color::Yellow),
+        Priority::Medium | Priority::Low => None,
+    };
+    if let Some(color) = priority_color.filter(|_| !done) {
+        spans.push(Span::styled(format!(" !{}", task.priority), Style::new().fg(color)));
+    }
+
+    if let Some(due) = task.due_date_datetime().filter(|_| !done) {
+        let now = Local::now();
+        let style = if due < now { Style::new().fg(Color::Red) } else { Style::new().dark_gray() };
+        spans.push(Span::styled(format!(" ({})", relative_due(due, now)), style));
+    }
+
+    if !task.tags.is_empty() {
+        let tags: Vec<String> = task.tags.iter().map(|t| format!("#{}", t)).collect();
+        spans.push(Span::styled(format!(" {}", tags.join(" ")), Style::new().fg(Color::Blue)));
+    }
+
+    Line::from(spans)
+}
+
+fn draw_input(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let prompt = match app.mode {
+        Mode::Normal => {
+            let line = match (app.filter_error(), &app.message) {
+                (Some(error), _) => Line::from(error).red(),
+                (None, Some(message)) => Line::from(message.as_str()),
+                (None, None) => Line::default(),
+            };
+            frame.render_widget(Paragraph::new(line), area);
+            return;
+        }
+        Mode::Filter => "Filter (text or /tag, /status, /p, /due): ",
+        Mode::EditTitle(_) => "Title: ",
+        Mode::AddTask { parent_id: Some(_) } => "New subtask (title /p high /tag x): ",
+        Mode::AddTask { parent_id: None } => "New task (title /p high /tag x): ",
+    };
+
+    let line = Line::from(vec![Span::raw(prompt).bold(), Span::raw(app.input.as_str())]);
+    frame.render_widget(Paragraph::new(line), area);
+
+    let cursor = area.x + (prompt.chars().count() + app.input.chars().count()) as u16;
+    frame.set_cursor_position(Position::new(cursor.min(area.right().saturating_sub(1)), area.y));
+}
@@ -0,0 +1,68 @@
+use chrono::Local;
+
+#[derive(Debug)]
+pub struct SavedView {
+    pub name: String,
+    pub query: String,
+    pub project_id: Option<u32>,
+    pub created_at: i64,
+}
+
+impl SavedView {
+    pub fn new(name: &str, query: &str, project_id: Option<u32>) -> Result<Self, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("View name cannot be empty".to_string());
+        }
+        if matches!(name, "save" | "list" | "delete") {
+            return Err(format!("'{}' is reserved and cannot be used as a view name", name));
+        }
+
+        Ok(SavedView {
+            name: name.to_string(),
+            query: query.trim().to_string(),
+            project_id,
+            created_at: Local::now().timestamp(),
+        })
+    }
+}
+
+// The view a name refers to: inside a project its own view, then the global one.
+// Outside a project the global view, or the only project view with that name.
+pub fn pick_view(views: Vec<SavedView>, project_id: Option<u32>) -> Result<Option<SavedView>, String> {
+    let (global, scoped): (Vec<SavedView>, Vec<SavedView>) = views.into_iter().partition(|v| v.project_id.is_none());
+
+    if project_id.is_some() {
+        let own = scoped.into_iter().find(|v| v.project_id == project_id);
+        return Ok(own.or_else(|| global.into_iter().next()));
+    }
+    if let Some(view) = global.into_iter().next() {
+        return Ok(Some(view));
+    }
+    match scoped.len() {
+        0 | 1 => Ok(scoped.into_iter().next()),
+        _ => Err("Several projects have a view with this name; '/use' one of them first".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(query: &str, project_id: Option<u32>) -> SavedView {
+        SavedView::new("today", query, project_id).unwrap()
+    }
+
+    #[test]
+    fn test_pick_view_prefers_current_project() {
+        let views = || vec![view("/p high", Some(1)), view("/p low", Some(2)), view("/status todo", None)];
+
+        assert_eq!(pick_view(views(), Some(2)).unwrap().unwrap().query, "/p low");
+        assert_eq!(pick_view(views(), Some(3)).unwrap().unwrap().query, "/status todo");
+        assert_eq!(pick_view(views(), None).unwrap().unwrap().query, "/status todo");
+
+        // Another project's view never runs inside a project
+        assert!(pick_view(vec![view("/p high", Some(1))], Some(2)).unwrap().is_none());
+        assert!(pick_view(vec![view("/p high", Some(1)), view("/p low", Some(2))], None).is_err());
+    }
+}
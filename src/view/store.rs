@@ -0,0 +1,102 @@
+use crate::db;
+use crate::view::model::SavedView;
+use directories::ProjectDirs;
+use rusqlite::{Connection, Result};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+pub struct ViewStore {
+    conn: Rc<Connection>,
+}
+
+impl ViewStore {
+    fn map_row_to_view(row: &rusqlite::Row) -> Result<SavedView> {
+        Ok(SavedView {
+            name: row.get("name")?,
+            query: row.get("query")?,
+            project_id: row.get("project_id")?,
+            created_at: row.get("created_at")?,
+        })
+    }
+
+    pub fn new() -> Result<Self> {
+        let db_path = Self::get_db_path();
+        let conn = db::connect(&db_path)?;
+
+        let store = ViewStore { conn };
+        store.init_tables()?;
+        Ok(store)
+    }
+
+    fn get_db_path() -> PathBuf {
+        ProjectDirs::from("com", "you", "clara")
+            .expect("no valid home dir")
+            .data_local_dir()
+            .join("clara.db")
+    }
+
+    fn init_tables(&self) -> Result<()> {
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS saved_views (
+                id           INTEGER PRIMARY KEY,
+                name         TEXT NOT NULL,
+                query        TEXT NOT NULL,
+                project_id   INTEGER,
+                created_at   INTEGER NOT NULL,
+                FOREIGN KEY(project_id) REFERENCES projects(id)
+            )",
+            [],
+        )?;
+        // A name is unique within its project; global views use project 0
+        self.conn.execute(
+            "CREATE UNIQUE INDEX IF NOT EXISTS saved_views_scope ON saved_views (name, COALESCE(project_id, 0))",
+            [],
+        )?;
+        Ok(())
+    }
+
+    // Saving under a name that already exists in the same scope replaces that view
+    pub fn save(&self, view: &SavedView) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO saved_views (name, query, project_id, created_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(name, COALESCE(project_id, 0)) DO UPDATE SET query = excluded.query",
+            rusqlite::params![view.name, view.query, view.project_id, view.created_at],
+        )?;
+        Ok(())
+    }
+
+    pub fn list(&self) -> Result<Vec<SavedView>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT name, query, project_id, created_at FROM saved_views ORDER BY name")?;
+        let view_iter = stmt.query_map([], Self::map_row_to_view)?;
+
+        let mut views = Vec::new();
+        for view in view_iter {
+            views.push(view?);
+        }
+        Ok(views)
+    }
+
+    // Every view with this name, one per project plus the global one
+    pub fn find_by_name(&self, name: &str) -> Result<Vec<SavedView>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT name, query, project_id, created_at FROM saved_views WHERE name = ?1")?;
+        let view_iter = stmt.query_map([name], Self::map_row_to_view)?;
+
+        let mut views = Vec::new();
+        for view in view_iter {
+            views.push(view?);
+        }
+        Ok(views)
+    }
+
+    pub fn delete(&self, name: &str, project_id: Option<u32>) -> Result<usize> {
+        self.conn.execute(
+            "DELETE FROM saved_views WHERE name = ?1 AND project_id IS ?2",
+            rusqlite::params![name, project_id],
+        )
+    }
+}